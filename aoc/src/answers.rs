@@ -0,0 +1,165 @@
+use std::{collections::BTreeMap, fmt::Display, path::PathBuf, str::FromStr};
+
+use anyhow::{bail, Context};
+
+use crate::input::workspace_dir;
+
+/// Accepted answers for the real inputs, stored one per line as `day part answer`.
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let answers = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|(i, line)| -> anyhow::Result<_> {
+                let mut words = line.split_whitespace();
+                let mut next = || {
+                    words
+                        .next()
+                        .with_context(|| format!("Invalid answer on line {}", i + 1))
+                };
+
+                let day = next()?.parse()?;
+                let part = next()?.parse()?;
+                let answer = next()?.to_string();
+
+                if let Some(extra) = words.next() {
+                    bail!("Unexpected {extra:?} after the answer on line {}", i + 1);
+                }
+
+                Ok(((day, part), answer))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { answers })
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day part answer")?;
+
+        for ((day, part), answer) in &self.answers {
+            writeln!(f, "{day} {part} {answer}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Answers {
+    pub fn path() -> PathBuf {
+        workspace_dir().join("answers.txt")
+    }
+
+    pub fn load() -> anyhow::Result<Self> {
+        let path = Self::path();
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?
+            .parse()
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let path = Self::path();
+
+        std::fs::write(&path, self.to_string())
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Answers are stored as one word, so can't be empty or contain whitespace.
+    pub fn record(&mut self, day: u8, part: u8, answer: String) -> anyhow::Result<()> {
+        if answer.is_empty() || answer.contains(char::is_whitespace) {
+            bail!("Can't record {answer:?}, answers must be one word");
+        }
+
+        self.answers.insert((day, part), answer);
+
+        Ok(())
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong(expected.to_string()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong(String),
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "✓"),
+            Self::Wrong(expected) => write!(f, "✗ (expected {expected})"),
+            Self::Unknown => write!(f, "? (unknown)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() -> anyhow::Result<()> {
+        let mut answers = Answers::default();
+        answers.record(5, 2, "46".to_string())?;
+        answers.record(1, 1, "142".to_string())?;
+
+        let text = answers.to_string();
+        assert_eq!(text, "# day part answer\n1 1 142\n5 2 46\n");
+        assert_eq!(text.parse::<Answers>()?, answers);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check() -> anyhow::Result<()> {
+        let answers = "1 1 142\n\n# comment\n1 2 281\n".parse::<Answers>()?;
+
+        assert_eq!(answers.check(1, 1, "142"), Verdict::Correct);
+        assert_eq!(
+            answers.check(1, 2, "142"),
+            Verdict::Wrong("281".to_string())
+        );
+        assert_eq!(answers.check(2, 1, "8"), Verdict::Unknown);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        assert!("1 1".parse::<Answers>().is_err());
+        assert!("one 1 142".parse::<Answers>().is_err());
+        assert!("1 1 142 junk".parse::<Answers>().is_err());
+
+        let mut answers = Answers::default();
+        assert!(answers.record(1, 1, "1 2".to_string()).is_err());
+        assert!(answers.record(1, 1, String::new()).is_err());
+        assert_eq!(answers, Answers::default());
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use aoc_common::Parser;
+
+use crate::solve;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Stats {
+    pub mean: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let min = *samples.iter().min()?;
+        let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / samples.len() as f64;
+
+        Some(Self {
+            mean,
+            min,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = format!(
+            "{:.2?} ± {:.2?} (min {:.2?})",
+            self.mean, self.stddev, self.min
+        );
+
+        // Pad the whole cell, as durations don't support width
+        f.pad(&text)
+    }
+}
+
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+fn time<T>(f: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<(T, Duration)> {
+    let start = Instant::now();
+    let output = f()?;
+
+    Ok((output, start.elapsed()))
+}
+
+/// Times parsing and each part separately, over `iterations` runs.
+pub fn bench(
+    day: u8,
+    parser: Parser,
+    input: &str,
+    iterations: u32,
+    part: Option<u8>,
+) -> anyhow::Result<DayBench> {
+    let mut samples = [vec![], vec![], vec![]];
+
+    for _ in 0..iterations {
+        let (solution, parse_time) = time(|| parser(input))?;
+        samples[0].push(parse_time);
+
+        for n in [1, 2]
+            .into_iter()
+            .filter(|n| part.is_none_or(|part| part == *n))
+        {
+            let (_, part_time) = time(|| solve(solution.as_ref(), n))?;
+            samples[n as usize].push(part_time);
+        }
+    }
+
+    let [parse, part1, part2] = samples.map(|samples| Stats::from_samples(&samples));
+
+    Ok(DayBench {
+        day,
+        parse: parse.unwrap_or(Stats {
+            mean: Duration::ZERO,
+            min: Duration::ZERO,
+            stddev: Duration::ZERO,
+        }),
+        part1,
+        part2,
+    })
+}
+
+pub fn print_table(benches: &[DayBench]) {
+    let cell =
+        |stats: Option<Stats>| stats.map_or_else(|| "-".to_string(), |stats| stats.to_string());
+
+    println!("{:>3} | {:<36} | {:<36} | Part 2", "Day", "Parse", "Part 1");
+    println!("{:-<3}-+-{:-<36}-+-{:-<36}-+-{:-<36}", "", "", "", "");
+
+    for bench in benches {
+        println!(
+            "{:>3} | {:<36} | {:<36} | {}",
+            bench.day,
+            bench.parse,
+            cell(bench.part1),
+            cell(bench.part2)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_millis);
+
+        assert_eq!(
+            Stats::from_samples(&samples),
+            Some(Stats {
+                mean: Duration::from_millis(5),
+                min: Duration::from_millis(2),
+                stddev: Duration::from_millis(2),
+            })
+        );
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_common::{parse_dyn, Parser};
+
+pub const DAYS: &[(u8, Parser)] = &[
+    (1, parse_dyn::<day1::Day>),
+    (2, parse_dyn::<day2::Day>),
+    (3, parse_dyn::<day3::Day>),
+    (4, parse_dyn::<day4::Day>),
+    (5, parse_dyn::<day5::Day>),
+    (6, parse_dyn::<day6::Day>),
+    (7, parse_dyn::<day7::Day>),
+    (8, parse_dyn::<day8::Day>),
+    (9, parse_dyn::<day9::Day>),
+];
+
+pub fn parser(day: u8) -> Option<Parser> {
+    DAYS.iter()
+        .find(|(number, _)| *number == day)
+        .map(|(_, parser)| *parser)
+}
@@ -0,0 +1,195 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+
+pub const YEAR: u16 = 2023;
+
+/// How inputs are downloaded, so the fetcher can be tested without the network.
+pub trait Transport {
+    fn get(&self, url: &str, session: &str) -> anyhow::Result<String>;
+}
+
+impl<F: Fn(&str, &str) -> anyhow::Result<String>> Transport for F {
+    fn get(&self, url: &str, session: &str) -> anyhow::Result<String> {
+        self(url, session)
+    }
+}
+
+pub struct HttpTransport;
+
+impl Transport for HttpTransport {
+    fn get(&self, url: &str, session: &str) -> anyhow::Result<String> {
+        ureq::get(url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", "github.com/arlohb/aoc2023-rs")
+            .call()
+            .with_context(|| format!("Failed to fetch {url}"))?
+            .into_string()
+            .with_context(|| format!("Failed to read response from {url}"))
+    }
+}
+
+/// Downloads puzzle inputs, keeping a copy of each in the cache
+/// so they are only ever downloaded once.
+pub struct Fetcher<T: Transport> {
+    transport: T,
+    base_url: String,
+    cache_dir: PathBuf,
+    session: Option<String>,
+}
+
+impl<T: Transport> Fetcher<T> {
+    pub fn new(transport: T, cache_dir: PathBuf) -> Self {
+        Self {
+            transport,
+            base_url: "https://adventofcode.com".to_string(),
+            cache_dir,
+            session: None,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn with_session(mut self, session: Option<String>) -> Self {
+        self.session = session;
+        self
+    }
+
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{day}.txt"))
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        let path = self.cache_path(year, day);
+
+        if path.exists() {
+            return std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()));
+        }
+
+        let Some(session) = &self.session else {
+            bail!("Input for {year} day {day} is not cached, and no session token was given");
+        };
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let input = self.transport.get(&url, session)?;
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        std::fs::write(&path, &input)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        Ok(input)
+    }
+}
+
+/// `$AOC_CACHE_DIR`, or an aoc directory in the user's cache directory.
+pub fn default_cache_dir() -> anyhow::Result<PathBuf> {
+    if let Some(dir) = std::env::var_os("AOC_CACHE_DIR") {
+        return Ok(PathBuf::from(dir));
+    }
+
+    if let Some(dir) = std::env::var_os("XDG_CACHE_HOME") {
+        return Ok(PathBuf::from(dir).join("aoc"));
+    }
+
+    let home =
+        std::env::var_os("HOME").context("Could not find a cache directory, set AOC_CACHE_DIR")?;
+
+    Ok(PathBuf::from(home).join(".cache").join("aoc"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn caches_inputs() -> anyhow::Result<()> {
+        let requests = Cell::new(0);
+        let transport = |url: &str, session: &str| {
+            requests.set(requests.get() + 1);
+            assert_eq!(url, "https://adventofcode.com/2023/day/5/input");
+            assert_eq!(session, "token");
+            Ok("seeds: 1 2\n".to_string())
+        };
+
+        let dir = temp_dir("cache");
+        let fetcher = Fetcher::new(transport, dir.clone()).with_session(Some("token".to_string()));
+
+        assert_eq!(fetcher.input(2023, 5)?, "seeds: 1 2\n");
+        assert_eq!(fetcher.input(2023, 5)?, "seeds: 1 2\n");
+        assert_eq!(requests.get(), 1);
+        assert!(dir.join("2023").join("day5.txt").exists());
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn needs_session_when_not_cached() {
+        let transport = |_: &str, _: &str| -> anyhow::Result<String> { panic!("Should not fetch") };
+        let fetcher = Fetcher::new(transport, temp_dir("session"));
+
+        assert!(fetcher.input(2023, 1).is_err());
+    }
+
+    #[test]
+    fn http_transport() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+
+        // A stand in for the real server, which serves a single request
+        let server = thread::spawn(move || -> anyhow::Result<Vec<String>> {
+            let (mut stream, _) = listener.accept()?;
+            let headers = BufReader::new(&stream)
+                .lines()
+                .take_while(|line| line.as_ref().is_ok_and(|line| !line.is_empty()))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let body = "Time: 7\nDistance: 9\n";
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )?;
+
+            Ok(headers)
+        });
+
+        let dir = temp_dir("http");
+        let fetcher = Fetcher::new(HttpTransport, dir.clone())
+            .with_base_url(format!("http://{addr}"))
+            .with_session(Some("token".to_string()));
+
+        assert_eq!(fetcher.input(2023, 6)?, "Time: 7\nDistance: 9\n");
+
+        let headers = server.join().expect("Server panicked")?;
+        assert_eq!(headers[0], "GET /2023/day/6/input HTTP/1.1");
+        assert!(headers
+            .iter()
+            .any(|header| header.eq_ignore_ascii_case("cookie: session=token")));
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+use clap::Args;
+
+#[derive(Args)]
+pub struct InputArgs {
+    /// Read the input from this file, or from stdin if it is -
+    #[arg(short, long, conflicts_with = "variant")]
+    input: Option<PathBuf>,
+    /// Read the day's input with this name, e.g. example reads dayN/example.txt
+    #[arg(short, long, default_value = "input")]
+    variant: String,
+}
+
+impl InputArgs {
+    pub fn source(&self) -> Input {
+        match &self.input {
+            Some(path) if path == Path::new("-") => Input::Stdin,
+            Some(path) => Input::Path(path.clone()),
+            None => Input::Variant(self.variant.clone()),
+        }
+    }
+}
+
+pub enum Input {
+    Variant(String),
+    Path(PathBuf),
+    Stdin,
+}
+
+/// The root of the workspace, where the day directories are.
+pub fn workspace_dir() -> &'static Path {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir)
+}
+
+impl Input {
+    /// Whether this is the day's real puzzle input, which answers are recorded for.
+    pub fn is_real(&self) -> bool {
+        matches!(self, Self::Variant(name) if name == "input")
+    }
+
+    pub fn read(&self, day: u8) -> anyhow::Result<String> {
+        match self {
+            Self::Variant(name) => {
+                let path = workspace_dir()
+                    .join(format!("day{day}"))
+                    .join(format!("{name}.txt"));
+
+                if !path.exists() {
+                    bail!(
+                        "Day {day} has no {name} input, expected it at {}",
+                        path.display()
+                    );
+                }
+
+                read_file(&path)
+            }
+            Self::Path(path) => {
+                if !path.exists() {
+                    bail!("Input file {} does not exist", path.display());
+                }
+
+                read_file(path)
+            }
+            Self::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Failed to read input from stdin")?;
+
+                Ok(input)
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> anyhow::Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
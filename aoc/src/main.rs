@@ -0,0 +1,481 @@
+#![warn(clippy::unwrap_used, clippy::pedantic, clippy::nursery)]
+#![allow(
+    clippy::module_inception,
+    clippy::module_name_repetitions,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+
+mod answers;
+mod bench;
+mod days;
+mod fetch;
+mod input;
+mod scaffold;
+
+use std::{ops::Range, path::PathBuf};
+
+use answers::{Answers, Verdict};
+use anyhow::{bail, Context};
+use aoc_common::{DynSolution, Locate, Solution};
+use clap::{Parser, Subcommand};
+use fetch::{Fetcher, HttpTransport};
+use input::{Input, InputArgs};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for a day
+    Run {
+        /// The day to run, e.g. 5
+        day: u8,
+        /// Only run one part, both are run by default
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Record an answer for a day's real input as accepted
+    Accept {
+        /// The day the answer is for
+        day: u8,
+        /// The part the answer is for
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// The accepted answer, defaults to the solution's current answer
+        answer: Option<String>,
+    },
+    /// Download a day's input into the cache, and into dayN/input.txt if it is missing
+    ///
+    /// The session token is read from $AOC_SESSION
+    #[allow(clippy::doc_markdown)]
+    Fetch {
+        /// The day to download
+        day: u8,
+        /// The year to download
+        #[arg(short, long, default_value_t = fetch::YEAR)]
+        year: u16,
+    },
+    /// Time parsing and each part of a day's solution, or of every day
+    ///
+    /// Build with --release for meaningful timings
+    Bench {
+        /// The day to time, all days are timed by default, which needs --variant rather than --input
+        day: Option<u8>,
+        /// Only time one part, both are timed by default
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// How many times to run each solution
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: u32,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Print day 3's schematic with its parts and gears highlighted
+    Schematic {
+        /// Also write the schematic as an SVG image to this file
+        #[arg(long)]
+        svg: Option<PathBuf>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Sum day 1's calibration values, reading numbers from a chosen vocabulary
+    Calibrate {
+        /// The language to read number words in
+        #[arg(short, long, default_value = "english")]
+        language: day1::Language,
+        /// Also read the word for zero
+        #[arg(long)]
+        zero: bool,
+        /// The largest number to read words for, at most 12
+        #[arg(long, default_value_t = 9, value_parser = clap::value_parser!(u32).range(1..=12))]
+        up_to: u32,
+        /// Read words from this file instead, each line being a word then its value
+        #[arg(short, long, conflicts_with_all = ["language", "zero", "up_to"])]
+        words: Option<PathBuf>,
+        /// Print the digits read from each line, and warn about lines without any
+        #[arg(short, long)]
+        diagnose: bool,
+        /// Fail on lines without any digits instead of counting them as 0
+        #[arg(short, long)]
+        strict: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Report which of day 2's games could have been played with each bag of cubes,
+    /// and the smallest bag every game could have been played with
+    Bag {
+        /// The cubes in each bag, e.g. "12 red, 13 green, 14 blue", the puzzle's bag by default
+        bags: Vec<day2::Bag>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Convert values between two of day 5's almanac categories, e.g. soil to humidity
+    Convert {
+        /// The category to convert from
+        from: String,
+        /// The category to convert to
+        to: String,
+        /// The values to convert
+        #[arg(required = true)]
+        values: Vec<u64>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Print the single map doing the same as all of day 5's maps between two categories
+    Compose {
+        /// The category to convert from
+        #[arg(default_value = "seed")]
+        from: String,
+        /// The category to convert to
+        #[arg(default_value = "location")]
+        to: String,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Find which of day 5's values convert into a range, e.g. the seeds landing in locations 0..100
+    Invert {
+        /// The start of the range to convert into
+        start: u64,
+        /// The end of the range to convert into, exclusive
+        end: u64,
+        /// The category to convert from
+        #[arg(long, default_value = "seed")]
+        from: String,
+        /// The category to convert to
+        #[arg(long, default_value = "location")]
+        to: String,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Check day 5's maps for overlapping, non-injective or overflowing lines
+    Validate {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Create the next day from aoc_template and register it in the runner
+    #[allow(clippy::doc_markdown)]
+    NewDay {
+        /// The day to create, defaults to the day after the last one
+        day: Option<u8>,
+    },
+}
+
+fn solve(solution: &dyn DynSolution, part: u8) -> anyhow::Result<String> {
+    if part == 1 {
+        solution.part1()
+    } else {
+        solution.part2()
+    }
+}
+
+fn run(day: u8, part: Option<u8>, input: &Input) -> anyhow::Result<()> {
+    let parser = days::parser(day).with_context(|| format!("Day {day} has not been solved"))?;
+    let answers = if input.is_real() {
+        Some(Answers::load()?)
+    } else {
+        None
+    };
+
+    let solution = parser(&input.read(day)?)?;
+    let mut wrong = 0;
+
+    for n in [1, 2]
+        .into_iter()
+        .filter(|n| part.is_none_or(|part| part == *n))
+    {
+        let answer = solve(solution.as_ref(), n)?;
+
+        if let Some(answers) = &answers {
+            let verdict = answers.check(day, n, &answer);
+
+            if matches!(verdict, Verdict::Wrong(_)) {
+                wrong += 1;
+            }
+
+            println!("Part {n}: {answer} {verdict}");
+        } else {
+            println!("Part {n}: {answer}");
+        }
+    }
+
+    if wrong > 0 {
+        bail!("{wrong} answer(s) did not match the accepted answers");
+    }
+
+    Ok(())
+}
+
+fn accept(day: u8, part: u8, answer: Option<String>) -> anyhow::Result<()> {
+    let answer = if let Some(answer) = answer {
+        answer
+    } else {
+        let parser = days::parser(day).with_context(|| format!("Day {day} has not been solved"))?;
+        let solution = parser(&Input::Variant("input".to_string()).read(day)?)?;
+
+        solve(solution.as_ref(), part)?
+    };
+
+    let mut answers = Answers::load()?;
+    answers.record(day, part, answer.clone())?;
+    answers.save()?;
+
+    println!("Accepted {answer} for day {day} part {part}");
+
+    Ok(())
+}
+
+fn fetch(day: u8, year: u16) -> anyhow::Result<()> {
+    let mut fetcher = Fetcher::new(HttpTransport, fetch::default_cache_dir()?)
+        .with_session(std::env::var("AOC_SESSION").ok());
+
+    if let Ok(base_url) = std::env::var("AOC_BASE_URL") {
+        fetcher = fetcher.with_base_url(base_url);
+    }
+
+    let input = fetcher.input(year, day)?;
+
+    println!("Cached at {}", fetcher.cache_path(year, day).display());
+
+    let day_dir = input::workspace_dir().join(format!("day{day}"));
+    let path = day_dir.join("input.txt");
+
+    if year == fetch::YEAR && day_dir.exists() && !path.exists() {
+        std::fs::write(&path, input)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        println!("Written to {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn bench(day: Option<u8>, part: Option<u8>, iterations: u32, input: &Input) -> anyhow::Result<()> {
+    // A file or stdin is only one day's input, so can't be shared between days
+    if day.is_none() && !matches!(input, Input::Variant(_)) {
+        bail!("--input needs a day to time, use --variant to time every day");
+    }
+
+    let days = match day {
+        Some(day) => vec![(
+            day,
+            days::parser(day).with_context(|| format!("Day {day} has not been solved"))?,
+        )],
+        None => days::DAYS.to_vec(),
+    };
+
+    let benches = days
+        .into_iter()
+        .map(|(day, parser)| bench::bench(day, parser, &input.read(day)?, iterations, part))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    bench::print_table(&benches);
+
+    Ok(())
+}
+
+fn schematic(svg: Option<PathBuf>, input: &Input) -> anyhow::Result<()> {
+    let input = input.read(3)?;
+    let schematic = input.parse::<day3::Schematic>().locate(&input)?;
+    let rules = day3::GearRules::default();
+
+    print!("{}", schematic.render_ansi(&rules));
+
+    if let Some(path) = svg {
+        std::fs::write(&path, schematic.render_svg(&rules))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+struct CalibrateArgs {
+    language: day1::Language,
+    zero: bool,
+    up_to: u32,
+    words: Option<PathBuf>,
+    diagnose: bool,
+    strict: bool,
+}
+
+fn calibrate(
+    CalibrateArgs {
+        language,
+        zero,
+        up_to,
+        words,
+        diagnose,
+        strict,
+    }: CalibrateArgs,
+    input: &Input,
+) -> anyhow::Result<()> {
+    let words = match words {
+        Some(path) => day1::Vocabulary::load(path)?,
+        None => day1::Vocabulary::spelled(language, u32::from(!zero)..=up_to),
+    };
+    let scanner = day1::DigitScanner::new(&day1::Vocabulary::digits().with_words(words));
+    let day = day1::Day::parse(&input.read(1)?)?;
+    let reports = day.diagnose(&scanner);
+
+    if diagnose {
+        for report in &reports {
+            println!("{report}");
+        }
+    }
+
+    let sum = if strict {
+        day1::strict_sum(&reports)?
+    } else {
+        reports.iter().filter_map(day1::LineReport::value).sum()
+    };
+
+    println!("{sum}");
+
+    Ok(())
+}
+
+fn possible_games(mut bags: Vec<day2::Bag>, input: &Input) -> anyhow::Result<()> {
+    let input = input.read(2)?;
+    let day = day2::Day::parse(&input).locate(&input)?;
+
+    if bags.is_empty() {
+        bags.push(day2::Bag::default());
+    }
+
+    println!("{}", day.feasibility(&bags));
+
+    Ok(())
+}
+
+fn convert(from: &str, to: &str, values: &[u64], input: &Input) -> anyhow::Result<()> {
+    let input = input.read(5)?;
+    let day = day5::Day::parse(&input).locate(&input)?;
+
+    for &value in values {
+        let converted = day.pipeline().convert(from, to, value)?;
+
+        println!("{from} {value} -> {to} {converted}");
+    }
+
+    Ok(())
+}
+
+fn compose(from: &str, to: &str, input: &Input) -> anyhow::Result<()> {
+    let input = input.read(5)?;
+    let day = day5::Day::parse(&input).locate(&input)?;
+
+    print!("{}", day.pipeline().compose(from, to)?);
+
+    Ok(())
+}
+
+fn invert(range: Range<u64>, from: &str, to: &str, input: &Input) -> anyhow::Result<()> {
+    let input = input.read(5)?;
+    let day = day5::Day::parse(&input).locate(&input)?;
+    let sources = day
+        .pipeline()
+        .invert_ranges(from, to, std::slice::from_ref(&range))?;
+
+    println!("{from} values converting to {to} {range:?}: {sources:?}");
+
+    if from == "seed" {
+        let listed = day
+            .seeds()
+            .iter()
+            .filter(|seed| sources.iter().any(|range| range.contains(seed)))
+            .collect::<Vec<_>>();
+
+        println!("Listed seeds: {listed:?}");
+        println!(
+            "Seed ranges: {:?}",
+            day5::intersect_ranges(&sources, day.seed_ranges())
+        );
+    }
+
+    Ok(())
+}
+
+fn validate(input: &Input) -> anyhow::Result<()> {
+    let input = input.read(5)?;
+    let day = day5::Day::parse(&input).locate(&input)?;
+    let issues = day.pipeline().validate();
+
+    if issues.is_empty() {
+        println!("No issues found");
+
+        return Ok(());
+    }
+
+    for issue in &issues {
+        println!("{issue}");
+    }
+
+    bail!("Found {} issue(s) in the maps", issues.len())
+}
+
+fn new_day(day: Option<u8>) -> anyhow::Result<()> {
+    let day = day.unwrap_or_else(|| days::DAYS.iter().map(|(day, _)| day + 1).max().unwrap_or(1));
+
+    scaffold::new_day(input::workspace_dir(), day)?;
+
+    println!("Created day{day}");
+
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    match Cli::parse().command {
+        Command::Run { day, part, input } => run(day, part, &input.source()),
+        Command::Accept { day, part, answer } => accept(day, part, answer),
+        Command::Fetch { day, year } => fetch(day, year),
+        Command::Bench {
+            day,
+            part,
+            iterations,
+            input,
+        } => bench(day, part, iterations, &input.source()),
+        Command::Schematic { svg, input } => schematic(svg, &input.source()),
+        Command::Calibrate {
+            language,
+            zero,
+            up_to,
+            words,
+            diagnose,
+            strict,
+            input,
+        } => calibrate(
+            CalibrateArgs {
+                language,
+                zero,
+                up_to,
+                words,
+                diagnose,
+                strict,
+            },
+            &input.source(),
+        ),
+        Command::Bag { bags, input } => possible_games(bags, &input.source()),
+        Command::Convert {
+            from,
+            to,
+            values,
+            input,
+        } => convert(&from, &to, &values, &input.source()),
+        Command::Compose { from, to, input } => compose(&from, &to, &input.source()),
+        Command::Invert {
+            start,
+            end,
+            from,
+            to,
+            input,
+        } => invert(start..end, &from, &to, &input.source()),
+        Command::Validate { input } => validate(&input.source()),
+        Command::NewDay { day } => new_day(day),
+    }
+}
@@ -0,0 +1,172 @@
+use std::path::Path;
+
+use anyhow::{bail, Context};
+
+fn read(path: &Path) -> anyhow::Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> anyhow::Result<()> {
+    std::fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn copy_dir(from: &Path, to: &Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(to).with_context(|| format!("Failed to create {}", to.display()))?;
+
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let name = entry.file_name();
+
+        if name == "target" {
+            continue;
+        }
+
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &to.join(name))?;
+        } else {
+            std::fs::copy(entry.path(), to.join(name))
+                .with_context(|| format!("Failed to copy {}", entry.path().display()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds the day as a dependency of the runner, after the last day.
+fn register_dependency(manifest: &str, day: u8) -> anyhow::Result<String> {
+    let is_day = |line: &&str| {
+        line.strip_prefix("day")
+            .and_then(|line| line.split_once(' '))
+            .is_some_and(|(n, _)| n.parse::<u8>().is_ok())
+    };
+
+    let mut lines = manifest.lines().collect::<Vec<_>>();
+    let last_day = lines
+        .iter()
+        .rposition(is_day)
+        .context("No days in the runner's Cargo.toml")?;
+
+    let dependency = format!("day{day} = {{ path = \"../day{day}\" }}");
+    lines.insert(last_day + 1, &dependency);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day to the end of the `DAYS` list.
+fn register_day(days: &str, day: u8) -> anyhow::Result<String> {
+    let mut lines = days.lines().collect::<Vec<_>>();
+    let end = lines
+        .iter()
+        .position(|line| *line == "];")
+        .context("No DAYS list in days.rs")?;
+
+    let entry = format!("    ({day}, parse_dyn::<day{day}::Day>),");
+    lines.insert(end, &entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates dayN from `aoc_template` in the workspace at `root`, and registers it in the runner.
+pub fn new_day(root: &Path, day: u8) -> anyhow::Result<()> {
+    let dir = root.join(format!("day{day}"));
+
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+
+    copy_dir(&root.join("aoc_template"), &dir)?;
+
+    let manifest_path = dir.join("Cargo.toml");
+    let manifest =
+        read(&manifest_path)?.replace("name = \"aoc_template\"", &format!("name = \"day{day}\""));
+    write(&manifest_path, &manifest)?;
+
+    write(&dir.join("example.txt"), "")?;
+
+    let runner_manifest_path = root.join("aoc").join("Cargo.toml");
+    let runner_manifest = register_dependency(&read(&runner_manifest_path)?, day)?;
+
+    let days_path = root.join("aoc").join("src").join("days.rs");
+    let days = register_day(&read(&days_path)?, day)?;
+
+    write(&runner_manifest_path, &runner_manifest)?;
+    write(&days_path, &days)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_dependency() -> anyhow::Result<()> {
+        let manifest = "[dependencies]\nanyhow = \"1.0\"\nday1 = { path = \"../day1\" }\nday2 = { path = \"../day2\" }\n\n[dev-dependencies]\n";
+
+        assert_eq!(
+            register_dependency(manifest, 3)?,
+            "[dependencies]\nanyhow = \"1.0\"\nday1 = { path = \"../day1\" }\nday2 = { path = \"../day2\" }\nday3 = { path = \"../day3\" }\n\n[dev-dependencies]\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn registers_day() -> anyhow::Result<()> {
+        let days = "pub const DAYS: &[(u8, Parser)] = &[\n    (1, parse_dyn::<day1::Day>),\n];\n";
+
+        assert_eq!(
+            register_day(days, 2)?,
+            "pub const DAYS: &[(u8, Parser)] = &[\n    (1, parse_dyn::<day1::Day>),\n    (2, parse_dyn::<day2::Day>),\n];\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn creates_day() -> anyhow::Result<()> {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+
+        std::fs::create_dir_all(root.join("aoc_template").join("src"))?;
+        std::fs::create_dir_all(root.join("aoc").join("src"))?;
+        write(
+            &root.join("aoc_template").join("Cargo.toml"),
+            "[package]\nname = \"aoc_template\"\n",
+        )?;
+        write(
+            &root.join("aoc_template").join("src").join("lib.rs"),
+            "// lib\n",
+        )?;
+        write(
+            &root.join("aoc").join("Cargo.toml"),
+            "[dependencies]\nday1 = { path = \"../day1\" }\n",
+        )?;
+        write(
+            &root.join("aoc").join("src").join("days.rs"),
+            "pub const DAYS: &[(u8, Parser)] = &[\n    (1, parse_dyn::<day1::Day>),\n];\n",
+        )?;
+
+        new_day(&root, 2)?;
+
+        assert_eq!(
+            read(&root.join("day2").join("Cargo.toml"))?,
+            "[package]\nname = \"day2\"\n"
+        );
+        assert_eq!(
+            read(&root.join("day2").join("src").join("lib.rs"))?,
+            "// lib\n"
+        );
+        assert_eq!(read(&root.join("day2").join("example.txt"))?, "");
+        assert!(
+            read(&root.join("aoc").join("Cargo.toml"))?.contains("day2 = { path = \"../day2\" }")
+        );
+        assert!(read(&root.join("aoc").join("src").join("days.rs"))?
+            .contains("(2, parse_dyn::<day2::Day>)"));
+
+        assert!(new_day(&root, 2).is_err());
+
+        std::fs::remove_dir_all(root)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,315 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context};
+
+use crate::ParseError;
+
+/// A position in a [`Grid`], ordered row by row.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Pos {
+    #[must_use]
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+impl PartialOrd for Pos {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Pos {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> anyhow::Result<Self> {
+        if cells.len() != width * height {
+            bail!(
+                "A {width}x{height} grid needs {} cells, not {}",
+                width * height,
+                cells.len()
+            );
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub const fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    #[must_use]
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            self.cells.get(pos.y * self.width + pos.x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            self.cells.get_mut(pos.y * self.width + pos.x)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, but a grid without columns has no rows worth visiting
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(if x < self.width { self.height } else { 0 })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos::new(x, y)))
+    }
+
+    pub fn find_all<'a>(
+        &'a self,
+        pred: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Pos> + 'a {
+        self.positions().filter(move |pos| pred(&self[*pos]))
+    }
+
+    fn offset(&self, pos: Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let pos = Pos::new(pos.x.checked_add_signed(dx)?, pos.y.checked_add_signed(dy)?);
+
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The positions above, below, left and right of `pos` that are in the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_4
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+
+    /// The positions around `pos`, including diagonals, that are in the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_8
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside of the grid"))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for line in &lines {
+            if line.chars().count() != width {
+                return Err(ParseError::new(line, format!("a row {width} cells wide")).into());
+            }
+
+            cells.extend(line.chars());
+        }
+
+        Self::new(width, lines.len(), cells).context("Invalid grid")
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Locate;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().expect("Valid grid")
+    }
+
+    #[test]
+    fn parse() -> anyhow::Result<()> {
+        let grid = grid();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Pos::new(2, 1)], 'f');
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let ragged = "abc\nde";
+        let error = ragged.parse::<Grid<char>>().locate(ragged).err();
+
+        assert_eq!(
+            error
+                .and_then(|e| e.downcast::<ParseError>().ok())
+                .and_then(|e| e.location)
+                .map(|location| location.line),
+            Some(2)
+        );
+        assert_eq!("".parse::<Grid<char>>()?.height(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.neighbours4(Pos::new(0, 0)).collect::<Vec<_>>(),
+            [Pos::new(1, 0), Pos::new(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8(Pos::new(1, 1)).collect::<Vec<_>>(),
+            [
+                Pos::new(0, 0),
+                Pos::new(1, 0),
+                Pos::new(2, 0),
+                Pos::new(0, 1),
+                Pos::new(2, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn map() {
+        let grid = grid().map(char::to_ascii_uppercase);
+
+        assert_eq!(grid.to_string(), "ABC\nDEF\n");
+        assert_eq!(grid.width(), 3);
+    }
+
+    #[test]
+    fn find_all() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.find_all(|c| "aeiou".contains(*c)).collect::<Vec<_>>(),
+            [Pos::new(0, 0), Pos::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn pos_order() {
+        assert!(Pos::new(5, 0) < Pos::new(0, 1));
+        assert!(Pos::new(0, 1) < Pos::new(1, 1));
+    }
+}
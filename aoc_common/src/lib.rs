@@ -2,13 +2,16 @@
 #![allow(
     clippy::module_inception,
     clippy::module_name_repetitions,
+    clippy::missing_errors_doc,
     clippy::cast_precision_loss,
     clippy::cast_possible_truncation,
     clippy::cast_sign_loss
 )]
 
-fn main() -> anyhow::Result<()> {
-    let _ = std::fs::read_to_string("input.txt")?;
+mod grid;
+pub mod parse;
+mod solution;
 
-    Ok(())
-}
+pub use grid::{Grid, Pos};
+pub use parse::{Locate, Location, ParseError};
+pub use solution::{parse_dyn, DynSolution, Parser, Solution};
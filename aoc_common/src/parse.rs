@@ -0,0 +1,265 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Where a [`ParseError`] is in the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    /// The line number, starting at 1
+    pub line: usize,
+    /// The column in characters, starting at 1
+    pub column: usize,
+    /// The whole line the error is on
+    pub snippet: String,
+}
+
+/// A piece of the input that couldn't be parsed, and what was expected instead.
+///
+/// Parsers only see the piece they are parsing, so the error starts without a [`Location`],
+/// which is found later with [`ParseError::locate`] given the whole input the piece was sliced from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub expected: String,
+    pub found: String,
+    pub location: Option<Location>,
+    // The address of `found`, to find where it was sliced from in the input
+    address: usize,
+}
+
+impl ParseError {
+    /// `found` should be a slice of the input, empty if something is missing there.
+    pub fn new(found: &str, expected: impl Into<String>) -> Self {
+        Self {
+            expected: expected.into(),
+            found: found.to_string(),
+            location: None,
+            address: found.as_ptr() as usize,
+        }
+    }
+
+    /// Finds where the error is in `input`, if it was sliced from `input` and isn't located yet.
+    #[must_use]
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+
+        if self.location.is_some() || !(start..=start + input.len()).contains(&self.address) {
+            return self;
+        }
+
+        let offset = self.address - start;
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        self.location = Some(Location {
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        });
+
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let found = if self.found.is_empty() {
+            "nothing".to_string()
+        } else {
+            format!("{:?}", self.found)
+        };
+
+        let Some(location) = &self.location else {
+            return write!(f, "Expected {}, found {found}", self.expected);
+        };
+
+        // The snippet has any trailing \r trimmed, so the column can be past its end
+        let width = location.snippet.chars().count();
+        let column = location.column.min(width + 1);
+
+        let gutter = " ".repeat(location.line.to_string().len());
+        let indent = " ".repeat(column - 1);
+        let remaining = width + 1 - column;
+        let carets = "^".repeat(self.found.chars().count().clamp(1, remaining.max(1)));
+
+        writeln!(
+            f,
+            "Line {}, column {}: expected {}, found {found}",
+            location.line, location.column, self.expected
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", location.line, location.snippet)?;
+        write!(f, "{gutter} | {indent}{carets}")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Locates any [`ParseError`] in the result within `input`.
+pub trait Locate {
+    #[must_use]
+    fn locate(self, input: &str) -> Self;
+}
+
+impl<T> Locate for anyhow::Result<T> {
+    fn locate(self, input: &str) -> Self {
+        self.map_err(|mut e| {
+            if let Some(error) = e.downcast_mut::<ParseError>() {
+                *error = error.clone().locate(input);
+            }
+
+            e
+        })
+    }
+}
+
+impl<T> Locate for Result<T, ParseError> {
+    fn locate(self, input: &str) -> Self {
+        self.map_err(|e| e.locate(input))
+    }
+}
+
+/// Splits `s` around the first `delimiter`.
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(&s[s.len()..], format!("{delimiter:?}")))
+}
+
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix).ok_or_else(|| {
+        let end = s
+            .char_indices()
+            .nth(prefix.chars().count())
+            .map_or(s.len(), |(i, _)| i);
+
+        ParseError::new(&s[..end], format!("{prefix:?}"))
+    })
+}
+
+pub fn strip_suffix<'a>(s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+    s.strip_suffix(suffix).ok_or_else(|| {
+        let start = s
+            .char_indices()
+            .rev()
+            .nth(suffix.chars().count().saturating_sub(1))
+            .map_or(0, |(i, _)| i);
+
+        ParseError::new(&s[start..], format!("{suffix:?}"))
+    })
+}
+
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(s, "a number"))
+}
+
+/// Parses the whitespace separated numbers in `s`.
+pub fn numbers<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    s.split_whitespace().map(number).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn located() {
+        let input = "Game 1: 3 blue\nGame 2: x blue\n";
+        let line = input.lines().nth(1).unwrap_or_default();
+        let error = number::<u32>(&line[8..9]).locate(input);
+
+        assert_eq!(
+            error.map_err(|e| e.to_string()),
+            Err([
+                "Line 2, column 9: expected a number, found \"x\"",
+                "  |",
+                "2 | Game 2: x blue",
+                "  |         ^",
+            ]
+            .join("\n"))
+        );
+    }
+
+    #[test]
+    fn missing() {
+        let input = "a: 1\nb 2";
+        let line = input.lines().nth(1).unwrap_or_default();
+        let error = split_once(line, ": ").locate(input).err();
+
+        assert_eq!(
+            error.as_ref().and_then(|e| e.location.clone()),
+            Some(Location {
+                line: 2,
+                column: 4,
+                snippet: "b 2".to_string()
+            })
+        );
+        assert!(error.is_some_and(|e| e.to_string().ends_with("2 | b 2\n  |    ^")));
+    }
+
+    #[test]
+    fn carriage_return() {
+        let input = "Card 1: 1 2\r";
+        let error = split_once(input, " | ").locate(input).err();
+
+        assert_eq!(
+            error
+                .as_ref()
+                .and_then(|e| e.location.clone())
+                .map(|l| l.column),
+            Some(13)
+        );
+        assert_eq!(
+            error.map(|e| e.to_string()),
+            Some(
+                [
+                    "Line 1, column 13: expected \" | \", found nothing",
+                    "  |",
+                    "1 | Card 1: 1 2",
+                    "  |            ^",
+                ]
+                .join("\n")
+            )
+        );
+    }
+
+    #[test]
+    fn prefix() {
+        let error = strip_prefix("Gaem 1", "Game ").err();
+
+        assert_eq!(error.map(|e| e.found), Some("Gaem ".to_string()));
+
+        let error = strip_suffix("(a, b]", ")").err();
+
+        assert_eq!(error.map(|e| e.found), Some("]".to_string()));
+    }
+
+    #[test]
+    fn unlocated() {
+        let error = number::<u32>("1x").locate(&String::from("elsewhere"));
+
+        assert_eq!(
+            error.map_err(|e| e.to_string()),
+            Err("Expected a number, found \"1x\"".to_string())
+        );
+    }
+
+    #[test]
+    fn through_anyhow() {
+        let input = "12\n3y";
+        let result = input
+            .lines()
+            .map(|line| Ok(number::<u32>(line)?))
+            .collect::<anyhow::Result<Vec<_>>>()
+            .locate(input);
+
+        assert_eq!(
+            result
+                .err()
+                .and_then(|e| e.downcast::<ParseError>().ok())
+                .and_then(|e| e.location)
+                .map(|location| (location.line, location.column)),
+            Some((2, 1))
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use std::fmt::Display;
+
+use crate::Locate;
+
+/// A day's puzzle, parsed once and then solved for either part.
+pub trait Solution: Sized {
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> anyhow::Result<Self>;
+
+    fn part1(&self) -> anyhow::Result<Self::Answer1>;
+
+    fn part2(&self) -> anyhow::Result<Self::Answer2>;
+}
+
+/// An object safe version of [`Solution`] with the answers already formatted,
+/// so different days can be stored and run side by side.
+pub trait DynSolution {
+    fn part1(&self) -> anyhow::Result<String>;
+
+    fn part2(&self) -> anyhow::Result<String>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn part1(&self) -> anyhow::Result<String> {
+        Solution::part1(self).map(|answer| answer.to_string())
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        Solution::part2(self).map(|answer| answer.to_string())
+    }
+}
+
+pub type Parser = fn(&str) -> anyhow::Result<Box<dyn DynSolution>>;
+
+/// Parses the input for `S`, erasing its type and locating any [`crate::ParseError`].
+/// Use `parse_dyn::<S>` as a [`Parser`].
+pub fn parse_dyn<S: Solution + 'static>(input: &str) -> anyhow::Result<Box<dyn DynSolution>> {
+    Ok(Box::new(S::parse(input).locate(input)?))
+}
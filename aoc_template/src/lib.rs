@@ -0,0 +1,45 @@
+#![warn(clippy::unwrap_used, clippy::pedantic, clippy::nursery)]
+#![allow(
+    clippy::module_inception,
+    clippy::module_name_repetitions,
+    clippy::missing_errors_doc,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+
+use aoc_common::Solution;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(_input: &str) -> anyhow::Result<Self> {
+        Ok(Self)
+    }
+
+    fn part1(&self) -> anyhow::Result<u64> {
+        Ok(0)
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example.txt"))?;
+
+        assert_eq!(day.part1()?, 0);
+        assert_eq!(day.part2()?, 0);
+
+        Ok(())
+    }
+}
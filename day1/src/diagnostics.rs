@@ -0,0 +1,103 @@
+use std::fmt::Display;
+
+use anyhow::bail;
+
+use crate::{calibration_value, DigitScanner};
+
+/// What was read from one line of the calibration document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineReport {
+    /// The line number, starting at 1
+    pub number: usize,
+    pub line: String,
+    pub digits: Vec<u32>,
+    pub first: Option<u32>,
+    pub last: Option<u32>,
+}
+
+impl LineReport {
+    #[must_use]
+    pub fn new(scanner: &DigitScanner, number: usize, line: &str) -> Self {
+        Self {
+            number,
+            line: line.to_string(),
+            digits: scanner.digits_in(line),
+            first: scanner.first(line),
+            last: scanner.last(line),
+        }
+    }
+
+    /// The calibration value, or `None` if the line has no digits and contributes nothing.
+    #[must_use]
+    pub fn value(&self) -> Option<u32> {
+        Some(calibration_value(self.first?, self.last?))
+    }
+}
+
+impl Display for LineReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.first, self.last, self.value()) {
+            (Some(first), Some(last), Some(value)) => write!(
+                f,
+                "{:>5}: {} -> {:?}, first {first}, last {last}, value {value}",
+                self.number, self.line, self.digits
+            ),
+            _ => write!(
+                f,
+                "{:>5}: {} -> warning: no digits, contributes nothing",
+                self.number, self.line
+            ),
+        }
+    }
+}
+
+/// Reports on every line, so malformed ones can be found.
+#[must_use]
+pub fn diagnose(scanner: &DigitScanner, lines: &[String]) -> Vec<LineReport> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| LineReport::new(scanner, i + 1, line))
+        .collect()
+}
+
+/// Sums the calibration values, failing on the first line without any digits.
+pub fn strict_sum(reports: &[LineReport]) -> anyhow::Result<u32> {
+    reports
+        .iter()
+        .try_fold(0, |sum, report| match report.value() {
+            Some(value) => Ok(sum + value),
+            None => bail!("Line {} has no digits: {:?}", report.number, report.line),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vocabulary;
+
+    #[test]
+    fn reports() -> anyhow::Result<()> {
+        let scanner = DigitScanner::new(&Vocabulary::puzzle());
+        let lines = ["xtwone3four", "abc", "7"].map(String::from);
+        let reports = diagnose(&scanner, &lines);
+
+        assert_eq!(reports[0].digits, [2, 1, 3, 4]);
+        assert_eq!(reports[0].value(), Some(24));
+        assert_eq!(reports[1].value(), None);
+        assert_eq!(reports[2].value(), Some(77));
+        assert_eq!(
+            reports[0].to_string(),
+            "    1: xtwone3four -> [2, 1, 3, 4], first 2, last 4, value 24"
+        );
+        assert_eq!(
+            reports[1].to_string(),
+            "    2: abc -> warning: no digits, contributes nothing"
+        );
+
+        assert!(strict_sum(&reports).is_err());
+        assert_eq!(strict_sum(&[reports[0].clone(), reports[2].clone()])?, 101);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+#![warn(clippy::unwrap_used, clippy::pedantic, clippy::nursery)]
+#![allow(
+    clippy::module_inception,
+    clippy::module_name_repetitions,
+    clippy::missing_errors_doc,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+
+mod diagnostics;
+mod matcher;
+mod scanner;
+mod vocabulary;
+
+use aoc_common::Solution;
+pub use diagnostics::{diagnose, strict_sum, LineReport};
+pub use matcher::{Match, Matcher};
+pub use scanner::DigitScanner;
+pub use vocabulary::{Language, Vocabulary};
+
+pub struct Day {
+    lines: Vec<String>,
+}
+
+/// Joins the first digit of `first` and the last digit of `last`,
+/// so words for numbers above 9 still give a two digit value.
+#[must_use]
+pub const fn calibration_value(first: u32, last: u32) -> u32 {
+    let mut leading = first;
+
+    while leading >= 10 {
+        leading /= 10;
+    }
+
+    10 * leading + last % 10
+}
+
+impl Day {
+    /// Sums the calibration values, counting lines without digits as 0,
+    /// see [`strict_sum`] to reject them instead.
+    #[must_use]
+    pub fn calibration_sum(&self, scanner: &DigitScanner) -> u32 {
+        self.lines
+            .iter()
+            .map(|line| {
+                calibration_value(
+                    scanner.first(line).unwrap_or(0),
+                    scanner.last(line).unwrap_or(0),
+                )
+            })
+            .sum()
+    }
+
+    #[must_use]
+    pub fn diagnose(&self, scanner: &DigitScanner) -> Vec<LineReport> {
+        diagnose(scanner, &self.lines)
+    }
+}
+
+impl Solution for Day {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            lines: input.lines().map(String::from).collect(),
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<u32> {
+        Ok(self.calibration_sum(&DigitScanner::new(&Vocabulary::digits())))
+    }
+
+    fn part2(&self) -> anyhow::Result<u32> {
+        Ok(self.calibration_sum(&DigitScanner::new(&Vocabulary::puzzle())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example.txt"))?;
+
+        assert_eq!(day.part1()?, 142);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example2.txt"))?;
+
+        assert_eq!(day.part2()?, 281);
+
+        Ok(())
+    }
+
+    #[test]
+    fn input() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../input.txt"))?;
+
+        assert_eq!(day.part1()?, 54644);
+        assert_eq!(day.part2()?, 53348);
+
+        Ok(())
+    }
+
+    #[test]
+    fn multi_digit() -> anyhow::Result<()> {
+        let day = Day::parse("twelve3\nx4nineteen\nzero")?;
+        let vocabulary =
+            Vocabulary::digits().with_words(Vocabulary::spelled(Language::English, 0..=12));
+
+        // nineteen is only read as nine
+        assert_eq!(
+            day.calibration_sum(&DigitScanner::new(&vocabulary)),
+            13 + 49
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::VecDeque;
+
+const NONE: usize = usize::MAX;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    /// The index of the pattern that matched
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// An Aho–Corasick automaton, finding every occurrence of any of its patterns in one pass.
+pub struct Matcher {
+    // The state to move to from each state on each byte
+    transitions: Vec<[usize; 256]>,
+    // The patterns ending at each state, including those ending at its suffixes
+    outputs: Vec<Vec<usize>>,
+    lens: Vec<usize>,
+}
+
+impl Matcher {
+    pub fn new<S: AsRef<[u8]>>(patterns: impl IntoIterator<Item = S>) -> Self {
+        let mut transitions = vec![[NONE; 256]];
+        let mut outputs = vec![vec![]];
+        let mut lens = vec![];
+
+        // Build a trie of the patterns
+        for (i, pattern) in patterns.into_iter().enumerate() {
+            let pattern = pattern.as_ref();
+            lens.push(pattern.len());
+
+            // An empty pattern would match everywhere, so is never matched instead
+            if pattern.is_empty() {
+                continue;
+            }
+
+            let mut state = 0;
+
+            for &b in pattern {
+                if transitions[state][b as usize] == NONE {
+                    transitions.push([NONE; 256]);
+                    outputs.push(vec![]);
+                    transitions[state][b as usize] = transitions.len() - 1;
+                }
+
+                state = transitions[state][b as usize];
+            }
+
+            outputs[state].push(i);
+        }
+
+        // Then fill in the missing transitions breadth first,
+        // following the failure link to the longest suffix that is also in the trie
+        let mut fail = vec![0; transitions.len()];
+        let mut queue = VecDeque::new();
+
+        for next in &mut transitions[0] {
+            match *next {
+                NONE => *next = 0,
+                child => queue.push_back(child),
+            }
+        }
+
+        while let Some(state) = queue.pop_front() {
+            let fallbacks = transitions[fail[state]];
+
+            for (next, fallback) in transitions[state].iter_mut().zip(fallbacks) {
+                match *next {
+                    NONE => *next = fallback,
+                    child => {
+                        fail[child] = fallback;
+                        let inherited = outputs[fallback].clone();
+                        outputs[child].extend(inherited);
+                        queue.push_back(child);
+                    }
+                }
+            }
+        }
+
+        Self {
+            transitions,
+            outputs,
+            lens,
+        }
+    }
+
+    /// The length of the longest pattern.
+    #[must_use]
+    pub fn max_len(&self) -> usize {
+        self.lens.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Every match in `haystack`, including overlapping ones, in order of where they end.
+    pub fn find_overlapping<'a>(&'a self, haystack: &'a [u8]) -> impl Iterator<Item = Match> + 'a {
+        haystack
+            .iter()
+            .enumerate()
+            .scan(0, |state, (i, &b)| {
+                *state = self.transitions[*state][b as usize];
+                Some((i + 1, *state))
+            })
+            .flat_map(move |(end, state)| {
+                self.outputs[state].iter().map(move |&pattern| Match {
+                    pattern,
+                    start: end - self.lens[pattern],
+                    end,
+                })
+            })
+    }
+
+    /// The match starting first in `haystack`, preferring the longest if several start together.
+    #[must_use]
+    pub fn find_first(&self, haystack: &[u8]) -> Option<Match> {
+        let max_len = self.max_len();
+        let mut best: Option<Match> = None;
+
+        for found in self.find_overlapping(haystack) {
+            if let Some(best) = best {
+                // Nothing ending this late could start before the best match
+                if found.end > best.start + max_len {
+                    break;
+                }
+            }
+
+            let is_better = best.is_none_or(|best| {
+                found.start < best.start || (found.start == best.start && found.end > best.end)
+            });
+
+            if is_better {
+                best = Some(found);
+            }
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(matcher: &Matcher, haystack: &str) -> Vec<(usize, usize, usize)> {
+        matcher
+            .find_overlapping(haystack.as_bytes())
+            .map(|m| (m.pattern, m.start, m.end))
+            .collect()
+    }
+
+    #[test]
+    fn overlapping() {
+        let matcher = Matcher::new(["he", "she", "his", "hers"]);
+
+        assert_eq!(
+            matches(&matcher, "ushers"),
+            [(1, 1, 4), (0, 2, 4), (3, 2, 6)]
+        );
+        assert_eq!(
+            matches(&matcher, "ahishe"),
+            [(2, 1, 4), (1, 3, 6), (0, 4, 6)]
+        );
+        assert_eq!(matches(&matcher, "xyz"), []);
+    }
+
+    #[test]
+    fn shared_letters() {
+        let matcher = Matcher::new(["one", "two", "eight"]);
+
+        assert_eq!(
+            matches(&matcher, "eightwone"),
+            [(2, 0, 5), (1, 4, 7), (0, 6, 9)]
+        );
+    }
+
+    #[test]
+    fn first() {
+        let matcher = Matcher::new(["eight", "eighteen", "teen", "1"]);
+
+        assert_eq!(
+            matcher.find_first(b"xeighteen1"),
+            Some(Match {
+                pattern: 1,
+                start: 1,
+                end: 9
+            })
+        );
+        assert_eq!(
+            matcher.find_first(b"x1eighteen"),
+            Some(Match {
+                pattern: 3,
+                start: 1,
+                end: 2
+            })
+        );
+        assert_eq!(matcher.find_first(b"nothing"), None);
+    }
+
+    #[test]
+    fn empty_pattern() {
+        let matcher = Matcher::new(["", "a"]);
+
+        assert_eq!(matches(&matcher, "aa"), [(1, 0, 1), (1, 1, 2)]);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{matcher::Matcher, vocabulary::Vocabulary};
+
+/// Finds the numbers in a calibration line, written in any of the words of a [`Vocabulary`].
+pub struct DigitScanner {
+    forward: Matcher,
+    // The patterns reversed, to find the last digit by scanning from the end
+    backward: Matcher,
+    values: Vec<u32>,
+}
+
+impl DigitScanner {
+    #[must_use]
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let (patterns, values): (Vec<_>, Vec<_>) = vocabulary
+            .iter()
+            .map(|(word, value)| (word.as_bytes().to_vec(), value))
+            .unzip();
+
+        let reversed = patterns.iter().map(|pattern| {
+            let mut pattern = pattern.clone();
+            pattern.reverse();
+            pattern
+        });
+
+        Self {
+            backward: Matcher::new(reversed),
+            forward: Matcher::new(&patterns),
+            values,
+        }
+    }
+
+    /// Every number in `line` in order, including ones whose words overlap, like "eightwo".
+    #[must_use]
+    pub fn digits_in(&self, line: &str) -> Vec<u32> {
+        let mut matches = self
+            .forward
+            .find_overlapping(line.as_bytes())
+            .collect::<Vec<_>>();
+        matches.sort_by_key(|m| m.start);
+
+        matches
+            .into_iter()
+            .map(|m| self.values[m.pattern])
+            .collect()
+    }
+
+    #[must_use]
+    pub fn first(&self, line: &str) -> Option<u32> {
+        self.forward
+            .find_first(line.as_bytes())
+            .map(|m| self.values[m.pattern])
+    }
+
+    #[must_use]
+    pub fn last(&self, line: &str) -> Option<u32> {
+        let mut reversed = line.as_bytes().to_vec();
+        reversed.reverse();
+
+        self.backward
+            .find_first(&reversed)
+            .map(|m| self.values[m.pattern])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vocabulary::Language;
+
+    #[test]
+    fn puzzle() {
+        let scanner = DigitScanner::new(&Vocabulary::puzzle());
+
+        assert_eq!(scanner.digits_in("xtwone3four"), [2, 1, 3, 4]);
+        assert_eq!(scanner.first("xtwone3four"), Some(2));
+        assert_eq!(scanner.last("xtwone3four"), Some(4));
+        assert_eq!(scanner.last("5eightwo"), Some(2));
+        assert_eq!(scanner.first("abc"), None);
+    }
+
+    #[test]
+    fn digits() {
+        let scanner = DigitScanner::new(&Vocabulary::digits());
+
+        assert_eq!(scanner.digits_in("a1b2c3"), [1, 2, 3]);
+        assert_eq!(scanner.first("two1nine"), Some(1));
+        assert_eq!(scanner.last("two1nine"), Some(1));
+    }
+
+    #[test]
+    fn multi_digit() {
+        let vocabulary = Vocabulary::spelled(Language::English, 0..=12);
+        let scanner = DigitScanner::new(&vocabulary);
+
+        assert_eq!(scanner.digits_in("zeroneleven"), [0, 1, 11]);
+        assert_eq!(scanner.first("xseventeen"), Some(7));
+        assert_eq!(scanner.last("twelvex"), Some(12));
+    }
+}
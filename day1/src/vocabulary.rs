@@ -0,0 +1,190 @@
+use std::{collections::HashMap, ops::RangeInclusive, path::Path, str::FromStr};
+
+use anyhow::{bail, Context};
+use aoc_common::{parse, Locate, ParseError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    English,
+    German,
+    French,
+    Spanish,
+}
+
+impl Language {
+    /// The words for zero to twelve.
+    #[must_use]
+    pub const fn words(self) -> [&'static str; 13] {
+        match self {
+            Self::English => [
+                "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+                "ten", "eleven", "twelve",
+            ],
+            Self::German => [
+                "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+                "zehn", "elf", "zwölf",
+            ],
+            Self::French => [
+                "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+                "dix", "onze", "douze",
+            ],
+            Self::Spanish => [
+                "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+                "diez", "once", "doce",
+            ],
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "english" | "en" => Self::English,
+            "german" | "de" => Self::German,
+            "french" | "fr" => Self::French,
+            "spanish" | "es" => Self::Spanish,
+            _ => bail!("Unknown language {s}"),
+        })
+    }
+}
+
+/// The words that can stand for a number in a calibration line, and the numbers they stand for.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { words: vec![] }
+    }
+
+    /// The digits 0 to 9.
+    #[must_use]
+    pub fn digits() -> Self {
+        (0..=9).map(|n| (n.to_string(), n)).collect()
+    }
+
+    /// The words in `language` for the numbers in `values`, only zero to twelve are known.
+    #[must_use]
+    pub fn spelled(language: Language, values: RangeInclusive<u32>) -> Self {
+        language
+            .words()
+            .into_iter()
+            .zip(0..)
+            .filter(|(_, n)| values.contains(n))
+            .map(|(word, n)| (word.to_string(), n))
+            .collect()
+    }
+
+    /// The digits and the English words for one to nine, as in the puzzle.
+    #[must_use]
+    pub fn puzzle() -> Self {
+        Self::digits().with_words(Self::spelled(Language::English, 1..=9))
+    }
+
+    #[must_use]
+    pub fn with(mut self, word: impl Into<String>, value: u32) -> Self {
+        self.words.push((word.into(), value));
+        self
+    }
+
+    #[must_use]
+    pub fn with_words(mut self, other: Self) -> Self {
+        self.words.extend(other.words);
+        self
+    }
+
+    /// Reads a word list with a word and its value on each line, e.g. `dozen 12`.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+
+        text.parse::<Self>()
+            .locate(&text)
+            .with_context(|| format!("Invalid word list {}", path.display()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+}
+
+impl FromIterator<(String, u32)> for Vocabulary {
+    fn from_iter<T: IntoIterator<Item = (String, u32)>>(iter: T) -> Self {
+        Self {
+            words: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl FromStr for Vocabulary {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut seen = HashMap::new();
+
+        s.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let (word, value) = line
+                    .rsplit_once(char::is_whitespace)
+                    .ok_or_else(|| ParseError::new(line, "a word then its value"))?;
+                let word = word.trim_end();
+                let parsed = parse::number::<u32>(value)?;
+
+                if let Some(&other) = seen.get(word) {
+                    if other != parsed {
+                        return Err(ParseError::new(
+                            value,
+                            format!("{other}, as {word} was before"),
+                        ));
+                    }
+                }
+
+                seen.insert(word.to_string(), parsed);
+
+                Ok((word.to_string(), parsed))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spelled() {
+        let vocabulary = Vocabulary::spelled(Language::German, 10..=12);
+
+        assert_eq!(
+            vocabulary.iter().collect::<Vec<_>>(),
+            [("zehn", 10), ("elf", 11), ("zwölf", 12)]
+        );
+    }
+
+    #[test]
+    fn parse() -> anyhow::Result<()> {
+        let vocabulary =
+            "# numbers\nnought 0\n\ndozen  12\nbaker's dozen 13\n".parse::<Vocabulary>()?;
+
+        assert_eq!(
+            vocabulary.iter().collect::<Vec<_>>(),
+            [("nought", 0), ("dozen", 12), ("baker's dozen", 13)]
+        );
+        assert!("dozen".parse::<Vocabulary>().is_err());
+        assert!("dozen twelve".parse::<Vocabulary>().is_err());
+        assert!("dozen 12\ndozen 13".parse::<Vocabulary>().is_err());
+
+        Ok(())
+    }
+}
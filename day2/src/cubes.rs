@@ -0,0 +1,184 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use aoc_common::{parse, Locate, ParseError};
+
+/// A number of cubes of each colour, colours that aren't mentioned have none.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Set {
+    counts: BTreeMap<String, u32>,
+}
+
+impl FromStr for Set {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut counts = BTreeMap::new();
+
+        for s in s.split(", ") {
+            let (n_str, colour) = parse::split_once(s.trim(), " ")?;
+            let n: u32 = parse::number(n_str)?;
+
+            if colour.is_empty() || colour.contains(char::is_whitespace) {
+                return Err(ParseError::new(colour, "a colour"));
+            }
+
+            let count: &mut u32 = counts.entry(colour.to_string()).or_default();
+            *count = count.checked_add(n).ok_or_else(|| {
+                ParseError::new(
+                    n_str,
+                    format!("a count keeping {colour} within {}", u32::MAX),
+                )
+            })?;
+        }
+
+        Ok(Self { counts })
+    }
+}
+
+impl Display for Set {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let cubes = self
+            .counts
+            .iter()
+            .map(|(colour, n)| format!("{n} {colour}"))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, u32)> for Set {
+    /// Adds up the counts of repeated colours, stopping at `u32::MAX`.
+    fn from_iter<T: IntoIterator<Item = (S, u32)>>(iter: T) -> Self {
+        let mut counts = BTreeMap::new();
+
+        for (colour, n) in iter {
+            let count: &mut u32 = counts.entry(colour.into()).or_default();
+            *count = count.saturating_add(n);
+        }
+
+        Self { counts }
+    }
+}
+
+impl Set {
+    #[must_use]
+    pub fn get(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    /// The colours and their counts, in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts.iter().map(|(colour, n)| (colour.as_str(), *n))
+    }
+
+    /// Whether there are no more of any colour than in `other`.
+    #[must_use]
+    pub fn is_within(&self, other: &Self) -> bool {
+        self.iter().all(|(colour, n)| n <= other.get(colour))
+    }
+
+    /// The most of each colour in either set.
+    #[must_use]
+    pub fn max(&self, other: &Self) -> Self {
+        let mut counts = self.counts.clone();
+
+        for (colour, n) in other.iter() {
+            let count = counts.entry(colour.to_string()).or_default();
+            *count = (*count).max(n);
+        }
+
+        Self { counts }
+    }
+
+    /// The product of the counts of `colours`, or `None` if it doesn't fit in a `u64`.
+    pub fn power<'a>(&self, colours: impl IntoIterator<Item = &'a str>) -> Option<u64> {
+        colours.into_iter().try_fold(1, |power: u64, colour| {
+            power.checked_mul(u64::from(self.get(colour)))
+        })
+    }
+}
+
+/// The cubes in the bag, which limit what a game could have shown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bag {
+    pub limits: Set,
+}
+
+impl Default for Bag {
+    /// The puzzle's bag, 12 red, 13 green and 14 blue cubes.
+    fn default() -> Self {
+        Self {
+            limits: [("red", 12), ("green", 13), ("blue", 14)]
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+impl FromStr for Bag {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            limits: s.parse::<Set>().locate(s)?,
+        })
+    }
+}
+
+impl Display for Bag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.limits.fmt(f)
+    }
+}
+
+impl Bag {
+    /// The colours in the bag, in alphabetical order.
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.limits.iter().map(|(colour, _)| colour)
+    }
+
+    #[must_use]
+    pub fn allows(&self, set: &Set) -> bool {
+        set.is_within(&self.limits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sets() -> anyhow::Result<()> {
+        let a = "3 blue, 4 red, 1 blue".parse::<Set>()?;
+        let b = "2 teal, 1 red".parse::<Set>()?;
+
+        assert_eq!(a.to_string(), "4 blue, 4 red");
+        assert_eq!(a.max(&b).to_string(), "4 blue, 4 red, 2 teal");
+        assert_eq!(a.power(["red", "blue"]), Some(16));
+        assert_eq!(a.power(["red", "teal"]), Some(0));
+        assert!("3".parse::<Set>().is_err());
+
+        let big = "70000 red, 70000 green, 70000 blue, 70000 teal".parse::<Set>()?;
+        assert_eq!(big.power(["red", "green", "blue"]), Some(70000_u64.pow(3)));
+        assert_eq!(big.power(["red", "green", "blue", "teal"]), None);
+
+        let error = "4294967295 red, 1 red".parse::<Set>().err();
+        assert_eq!(error.map(|e| e.found), Some("1".to_string()));
+        assert!("three blue".parse::<Set>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn bags() -> anyhow::Result<()> {
+        let bag = "2 teal, 5 red".parse::<Bag>()?;
+
+        assert!(bag.allows(&"2 teal, 1 red".parse()?));
+        assert!(!bag.allows(&"3 teal".parse()?));
+        assert!(!bag.allows(&"1 blue".parse()?));
+        assert_eq!(Bag::default().to_string(), "14 blue, 13 green, 12 red");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt::Display;
+
+use crate::{Bag, Day, Set};
+
+/// Which games a bag could have been used for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BagReport {
+    pub bag: Bag,
+    /// The ids of the possible games, in the order they were played
+    pub possible: Vec<u32>,
+    pub total_games: usize,
+}
+
+impl BagReport {
+    #[must_use]
+    pub fn id_sum(&self) -> u32 {
+        self.possible.iter().sum()
+    }
+
+    #[must_use]
+    pub const fn allows_all(&self) -> bool {
+        self.possible.len() == self.total_games
+    }
+}
+
+impl Display for BagReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ids = self.possible.iter().map(u32::to_string).collect::<Vec<_>>();
+
+        write!(
+            f,
+            "{}: {}/{} games possible, ids summing to {} [{}]",
+            self.bag,
+            self.possible.len(),
+            self.total_games,
+            self.id_sum(),
+            ids.join(", ")
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Feasibility {
+    pub reports: Vec<BagReport>,
+    /// The smallest bag every game could have been played with
+    pub minimum: Bag,
+}
+
+impl Display for Feasibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for report in &self.reports {
+            writeln!(f, "{report}")?;
+        }
+
+        write!(f, "Smallest bag for every game: {}", self.minimum)
+    }
+}
+
+impl Day {
+    /// The smallest bag every game could have been played with.
+    #[must_use]
+    pub fn minimum_bag(&self) -> Bag {
+        Bag {
+            limits: self.games().iter().fold(Set::default(), |bag, game| {
+                bag.max(&game.minimum_bag().limits)
+            }),
+        }
+    }
+
+    #[must_use]
+    pub fn report(&self, bag: &Bag) -> BagReport {
+        BagReport {
+            bag: bag.clone(),
+            possible: self
+                .games()
+                .iter()
+                .filter(|game| game.is_possible(bag))
+                .map(|game| game.id)
+                .collect(),
+            total_games: self.games().len(),
+        }
+    }
+
+    /// Reports on which games each of `bags` makes possible.
+    #[must_use]
+    pub fn feasibility(&self, bags: &[Bag]) -> Feasibility {
+        Feasibility {
+            reports: bags.iter().map(|bag| self.report(bag)).collect(),
+            minimum: self.minimum_bag(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::*;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example.txt"))?;
+        let feasibility = day.feasibility(&[Bag::default(), "20 red, 13 green, 15 blue".parse()?]);
+
+        assert_eq!(feasibility.reports[0].possible, [1, 2, 5]);
+        assert!(!feasibility.reports[0].allows_all());
+        assert!(feasibility.reports[1].allows_all());
+        assert_eq!(feasibility.minimum.to_string(), "15 blue, 13 green, 20 red");
+        assert!(day.report(&feasibility.minimum).allows_all());
+        assert_eq!(
+            day.games()[0].minimum_bag().to_string(),
+            "6 blue, 2 green, 4 red"
+        );
+        assert_eq!(
+            feasibility.to_string().lines().next(),
+            Some("14 blue, 13 green, 12 red: 3/5 games possible, ids summing to 8 [1, 2, 5]")
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+#![warn(clippy::unwrap_used, clippy::pedantic, clippy::nursery)]
+#![allow(
+    clippy::module_inception,
+    clippy::module_name_repetitions,
+    clippy::missing_errors_doc,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+
+mod cubes;
+mod feasibility;
+
+use std::str::FromStr;
+
+use anyhow::Context;
+use aoc_common::{parse, ParseError, Solution};
+pub use cubes::{Bag, Set};
+pub use feasibility::{BagReport, Feasibility};
+
+pub struct Game {
+    pub id: u32,
+    pub infos: Vec<Set>,
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = parse::strip_prefix(s, "Game ")?;
+        let (id_str, s) = parse::split_once(s, ": ")?;
+
+        let infos = s
+            .split("; ")
+            .map(Set::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            id: parse::number(id_str)?,
+            infos,
+        })
+    }
+}
+
+impl Game {
+    #[must_use]
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.infos.iter().all(|info| bag.allows(info))
+    }
+
+    /// The smallest bag this game could have been played with.
+    #[must_use]
+    pub fn minimum_bag(&self) -> Bag {
+        Bag {
+            limits: self
+                .infos
+                .iter()
+                .fold(Set::default(), |bag, info| bag.max(info)),
+        }
+    }
+}
+
+pub struct Day {
+    games: Vec<Game>,
+}
+
+impl Day {
+    #[must_use]
+    pub fn games(&self) -> &[Game] {
+        &self.games
+    }
+
+    /// The sum of the ids of the games that could have been played with `bag`.
+    #[must_use]
+    pub fn possible_id_sum(&self, bag: &Bag) -> u32 {
+        self.report(bag).id_sum()
+    }
+}
+
+impl Solution for Day {
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            games: input
+                .lines()
+                .map(Game::from_str)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<u32> {
+        Ok(self.possible_id_sum(&Bag::default()))
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        let bag = Bag::default();
+
+        self.games.iter().try_fold(0, |sum: u64, game| {
+            game.minimum_bag()
+                .limits
+                .power(bag.colours())
+                .and_then(|power| sum.checked_add(power))
+                .with_context(|| format!("The powers overflow 64 bits at game {}", game.id))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Locate;
+
+    use super::*;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example.txt"))?;
+
+        assert_eq!(day.part1()?, 8);
+        assert_eq!(day.part2()?, 2286);
+
+        Ok(())
+    }
+
+    #[test]
+    fn large_counts() -> anyhow::Result<()> {
+        let day = Day::parse("Game 1: 70000 red, 70000 green, 1 blue\n")?;
+
+        assert_eq!(day.part2()?, 4_900_000_000);
+
+        let input = "Game 1: 4294967295 red, 1 red\n";
+        let error = Day::parse(input).locate(input).err();
+        let error = error.and_then(|e| e.downcast::<ParseError>().ok());
+
+        assert_eq!(error.and_then(|e| e.location).map(|l| l.column), Some(25));
+
+        Ok(())
+    }
+
+    #[test]
+    fn other_bag() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example.txt"))?;
+
+        assert_eq!(
+            day.possible_id_sum(&"20 red, 20 green, 20 blue".parse()?),
+            15
+        );
+        assert_eq!(day.possible_id_sum(&"4 red, 2 green, 6 blue".parse()?), 1);
+        assert_eq!(day.possible_id_sum(&"20 red, 20 green".parse()?), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        let input = "Game 1: 3 blue\nGame 2: 4 red, x green\n";
+        let error = Day::parse(input).locate(input).err();
+
+        assert_eq!(
+            error.map(|e| e.to_string()),
+            Some(
+                [
+                    "Line 2, column 16: expected a number, found \"x\"",
+                    "  |",
+                    "2 | Game 2: 4 red, x green",
+                    "  |                ^",
+                ]
+                .join("\n")
+            )
+        );
+    }
+
+    #[test]
+    fn input() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../input.txt"))?;
+
+        assert_eq!(day.part1()?, 2632);
+        assert_eq!(day.part2()?, 69629);
+
+        Ok(())
+    }
+}
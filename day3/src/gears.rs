@@ -0,0 +1,151 @@
+use std::ops::RangeInclusive;
+
+use crate::schematic::{Schematic, Symbol};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Combine {
+    Product,
+    Sum,
+}
+
+impl Combine {
+    /// Combines `values`, or `None` if the result doesn't fit in a `u64`.
+    pub fn apply(self, mut values: impl Iterator<Item = u64>) -> Option<u64> {
+        match self {
+            Self::Product => values.try_fold(1, u64::checked_mul),
+            Self::Sum => values.try_fold(0, u64::checked_add),
+        }
+    }
+}
+
+/// What makes a symbol a gear, and how its parts make its ratio.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GearRules {
+    pub symbols: Vec<char>,
+    /// How many numbers must touch the symbol
+    pub counts: RangeInclusive<usize>,
+    pub combine: Combine,
+}
+
+impl Default for GearRules {
+    /// The puzzle's rules, a `*` touching exactly two numbers, which are multiplied.
+    fn default() -> Self {
+        Self {
+            symbols: vec!['*'],
+            counts: 2..=2,
+            combine: Combine::Product,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Gear {
+    pub symbol: Symbol,
+    /// `None` if the parts combine to more than fits in a `u64`
+    pub ratio: Option<u64>,
+}
+
+impl Schematic {
+    /// Every gear under `rules`, row by row.
+    /// A number touching several gears counts towards all of them.
+    #[must_use]
+    pub fn gears(&self, rules: &GearRules) -> Vec<Gear> {
+        self.symbols()
+            .iter()
+            .filter(|symbol| rules.symbols.contains(&symbol.c))
+            .filter_map(|symbol| {
+                let parts = self.parts_touching(symbol.pos).collect::<Vec<_>>();
+
+                rules.counts.contains(&parts.len()).then(|| Gear {
+                    symbol: *symbol,
+                    ratio: rules
+                        .combine
+                        .apply(parts.iter().map(|number| u64::from(number.value))),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Pos;
+
+    use super::*;
+
+    fn ratios(schematic: &Schematic, rules: &GearRules) -> Vec<Option<u64>> {
+        schematic
+            .gears(rules)
+            .into_iter()
+            .map(|gear| gear.ratio)
+            .collect()
+    }
+
+    #[test]
+    fn default_rules() -> anyhow::Result<()> {
+        let schematic = include_str!("../example.txt").parse::<Schematic>()?;
+
+        assert_eq!(
+            schematic.gears(&GearRules::default())[0],
+            Gear {
+                symbol: Symbol {
+                    c: '*',
+                    pos: Pos::new(3, 1)
+                },
+                ratio: Some(16345),
+            }
+        );
+        assert_eq!(
+            ratios(&schematic, &GearRules::default()),
+            [Some(16345), Some(451_490)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn custom_rules() -> anyhow::Result<()> {
+        let schematic = include_str!("../example.txt").parse::<Schematic>()?;
+
+        let rules = GearRules {
+            symbols: vec!['*', '#', '+', '$'],
+            counts: 1..=usize::MAX,
+            combine: Combine::Sum,
+        };
+
+        assert_eq!(
+            ratios(&schematic, &rules),
+            [502, 633, 617, 592, 664, 1353].map(Some)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn shared_parts() -> anyhow::Result<()> {
+        let schematic = "2*3*4\n".parse::<Schematic>()?;
+
+        assert_eq!(
+            ratios(&schematic, &GearRules::default()),
+            [Some(6), Some(12)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflow() -> anyhow::Result<()> {
+        let schematic = "4000000000.4000000000\n..........*..........\n4000000000.4000000000\n"
+            .parse::<Schematic>()?;
+
+        let rules = GearRules {
+            counts: 1..=usize::MAX,
+            ..GearRules::default()
+        };
+
+        assert_eq!(ratios(&schematic, &rules), [None]);
+        assert_eq!(Combine::Sum.apply([u64::MAX, 1].into_iter()), None);
+
+        Ok(())
+    }
+}
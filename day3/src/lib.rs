@@ -0,0 +1,80 @@
+#![warn(clippy::unwrap_used, clippy::pedantic, clippy::nursery)]
+#![allow(
+    clippy::module_inception,
+    clippy::module_name_repetitions,
+    clippy::missing_errors_doc,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+
+mod gears;
+mod render;
+mod schematic;
+
+use anyhow::Context;
+use aoc_common::Solution;
+pub use gears::{Combine, Gear, GearRules};
+pub use render::Highlight;
+pub use schematic::{Number, Schematic, Symbol};
+
+pub struct Day {
+    schematic: Schematic,
+}
+
+impl Solution for Day {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            schematic: input.parse()?,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<u64> {
+        let sum = self
+            .schematic
+            .part_numbers()
+            .map(|number| u64::from(number.value))
+            .sum();
+
+        Ok(sum)
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        self.schematic
+            .gears(&GearRules::default())
+            .into_iter()
+            .try_fold(0, |sum: u64, gear| {
+                gear.ratio
+                    .and_then(|ratio| sum.checked_add(ratio))
+                    .with_context(|| format!("Gear ratios overflow at {}", gear.symbol.pos))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example.txt"))?;
+
+        assert_eq!(day.part1()?, 4361);
+        assert_eq!(day.part2()?, 467_835);
+
+        Ok(())
+    }
+
+    #[test]
+    fn input() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../input.txt"))?;
+
+        assert_eq!(day.part1()?, 540_025);
+        assert_eq!(day.part2()?, 84_584_891);
+
+        Ok(())
+    }
+}
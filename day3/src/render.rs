@@ -0,0 +1,178 @@
+use std::fmt::Write;
+
+use aoc_common::Grid;
+
+use crate::{gears::GearRules, schematic::Schematic};
+
+/// How a cell of the schematic is drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Highlight {
+    /// Part of a number touching a symbol, so counted in part 1
+    Part,
+    /// Part of a number touching no symbols
+    Ignored,
+    /// A symbol that is a gear under the rules
+    Gear,
+    Symbol,
+    Empty,
+}
+
+impl Highlight {
+    const fn ansi(self) -> &'static str {
+        match self {
+            Self::Part => "\x1b[32m",
+            Self::Ignored => "\x1b[31m",
+            Self::Gear => "\x1b[1;33m",
+            Self::Symbol => "\x1b[36m",
+            Self::Empty => "\x1b[2m",
+        }
+    }
+
+    const fn colour(self) -> &'static str {
+        match self {
+            Self::Part => "#4caf50",
+            Self::Ignored => "#f44336",
+            Self::Gear => "#ffc107",
+            Self::Symbol => "#03a9f4",
+            Self::Empty => "#555555",
+        }
+    }
+}
+
+impl Schematic {
+    #[must_use]
+    pub fn highlights(&self, rules: &GearRules) -> Grid<Highlight> {
+        let mut highlights = self.grid().map(|_| Highlight::Empty);
+
+        for symbol in self.symbols() {
+            highlights[symbol.pos] = Highlight::Symbol;
+        }
+        for gear in self.gears(rules) {
+            highlights[gear.symbol.pos] = Highlight::Gear;
+        }
+        for number in self.numbers() {
+            for pos in number.positions() {
+                highlights[pos] = if number.is_part() {
+                    Highlight::Part
+                } else {
+                    Highlight::Ignored
+                };
+            }
+        }
+
+        highlights
+    }
+
+    /// The schematic coloured with ANSI escape codes for printing to a terminal.
+    #[must_use]
+    pub fn render_ansi(&self, rules: &GearRules) -> String {
+        let highlights = self.highlights(rules);
+        let mut out = String::new();
+
+        for (row, highlights) in self.grid().rows().zip(highlights.rows()) {
+            let mut current = None;
+
+            for (c, highlight) in row.iter().zip(highlights) {
+                if current != Some(*highlight) {
+                    out.push_str("\x1b[0m");
+                    out.push_str(highlight.ansi());
+                    current = Some(*highlight);
+                }
+                out.push(*c);
+            }
+
+            out.push_str("\x1b[0m\n");
+        }
+
+        out
+    }
+
+    /// The same view as [`Schematic::render_ansi`], as an SVG image.
+    #[must_use]
+    pub fn render_svg(&self, rules: &GearRules) -> String {
+        const CELL_WIDTH: usize = 10;
+        const CELL_HEIGHT: usize = 16;
+
+        let highlights = self.highlights(rules);
+        let grid = self.grid();
+        let mut out = String::new();
+
+        // Writing to a String can't fail
+        let _ = writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="monospace" font-size="14">"#,
+            grid.width() * CELL_WIDTH,
+            grid.height() * CELL_HEIGHT,
+        );
+        let _ = writeln!(
+            out,
+            r##"<rect width="100%" height="100%" fill="#1e1e1e"/>"##
+        );
+
+        for pos in grid.positions() {
+            let c = match grid[pos] {
+                '&' => "&amp;".to_string(),
+                '<' => "&lt;".to_string(),
+                '>' => "&gt;".to_string(),
+                c => c.to_string(),
+            };
+
+            let _ = writeln!(
+                out,
+                r#"<text x="{}" y="{}" fill="{}">{c}</text>"#,
+                pos.x * CELL_WIDTH,
+                (pos.y + 1) * CELL_HEIGHT - 4,
+                highlights[pos].colour(),
+            );
+        }
+
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Pos;
+
+    use super::*;
+
+    #[test]
+    fn highlights() -> anyhow::Result<()> {
+        let schematic = include_str!("../example.txt").parse::<Schematic>()?;
+        let highlights = schematic.highlights(&GearRules::default());
+
+        assert_eq!(highlights[Pos::new(0, 0)], Highlight::Part);
+        assert_eq!(highlights[Pos::new(5, 0)], Highlight::Ignored);
+        assert_eq!(highlights[Pos::new(3, 1)], Highlight::Gear);
+        assert_eq!(highlights[Pos::new(3, 4)], Highlight::Symbol);
+        assert_eq!(highlights[Pos::new(3, 0)], Highlight::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ansi() -> anyhow::Result<()> {
+        let schematic = "1.\n*&\n".parse::<Schematic>()?;
+
+        assert_eq!(
+            schematic.render_ansi(&GearRules::default()),
+            "\x1b[0m\x1b[32m1\x1b[0m\x1b[2m.\x1b[0m\n\x1b[0m\x1b[36m*&\x1b[0m\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn svg() -> anyhow::Result<()> {
+        let schematic = "1.\n*&\n".parse::<Schematic>()?;
+        let svg = schematic.render_svg(&GearRules::default());
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(svg.contains(r##"<text x="0" y="12" fill="#4caf50">1</text>"##));
+        assert!(svg.contains(r##"<text x="10" y="28" fill="#03a9f4">&amp;</text>"##));
+
+        Ok(())
+    }
+}
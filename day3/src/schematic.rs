@@ -0,0 +1,250 @@
+use std::{collections::HashMap, ops::Range, str::FromStr};
+
+use aoc_common::{Grid, ParseError, Pos};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub row: usize,
+    pub columns: Range<usize>,
+    /// The positions of the symbols next to this number, row by row.
+    pub symbols: Vec<Pos>,
+}
+
+impl Number {
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.columns.clone().map(|x| Pos::new(x, self.row))
+    }
+
+    /// Whether the number touches a symbol, making it a part number.
+    #[must_use]
+    pub const fn is_part(&self) -> bool {
+        !self.symbols.is_empty()
+    }
+
+    /// The cells around the number, row by row.
+    fn neighbours(&self, grid: &Grid<char>) -> Vec<Pos> {
+        let mut neighbours = self
+            .positions()
+            .flat_map(|pos| grid.neighbours8(pos))
+            .filter(|pos| pos.y != self.row || !self.columns.contains(&pos.x))
+            .collect::<Vec<_>>();
+
+        neighbours.sort_unstable();
+        neighbours.dedup();
+        neighbours
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub c: char,
+    pub pos: Pos,
+}
+
+const fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+/// An engine schematic, with every number and symbol in it and which of them touch.
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    // The indices into numbers of the numbers touching each symbol
+    touching: HashMap<Pos, Vec<usize>>,
+}
+
+/// The numbers in `grid`, which was parsed from `input`.
+fn scan_numbers(grid: &Grid<char>, input: &str) -> Result<Vec<Number>, ParseError> {
+    let mut numbers = vec![];
+
+    for (y, row) in grid.rows().enumerate() {
+        // The number so far, or None once it's too large, and its start
+        let mut n_pair: Option<(Option<u32>, usize)> = None;
+
+        for (x, c) in row.iter().chain(std::iter::once(&'.')).enumerate() {
+            if let Some(digit) = c.to_digit(10) {
+                if let Some((n, _)) = &mut n_pair {
+                    *n = n
+                        .and_then(|n| n.checked_mul(10))
+                        .and_then(|n| n.checked_add(digit));
+                } else {
+                    n_pair = Some((Some(digit), x));
+                }
+            } else if let Some((value, start)) = n_pair.take() {
+                let value = value.ok_or_else(|| {
+                    let line = input.lines().nth(y).unwrap_or_default();
+                    let offsets = line
+                        .char_indices()
+                        .map(|(i, _)| i)
+                        .chain([line.len()])
+                        .collect::<Vec<_>>();
+
+                    ParseError::new(
+                        &line[offsets[start]..offsets[x]],
+                        format!("a number up to {}", u32::MAX),
+                    )
+                })?;
+
+                numbers.push(Number {
+                    value,
+                    row: y,
+                    columns: start..x,
+                    symbols: vec![],
+                });
+            }
+        }
+    }
+
+    Ok(numbers)
+}
+
+impl FromStr for Schematic {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = s.parse::<Grid<char>>()?;
+
+        let symbols = grid
+            .find_all(|c| is_symbol(*c))
+            .map(|pos| Symbol { c: grid[pos], pos })
+            .collect::<Vec<_>>();
+
+        let mut numbers = scan_numbers(&grid, s)?;
+        let mut touching = HashMap::<Pos, Vec<usize>>::new();
+
+        for (i, number) in numbers.iter_mut().enumerate() {
+            number.symbols = number
+                .neighbours(&grid)
+                .into_iter()
+                .filter(|pos| is_symbol(grid[*pos]))
+                .collect();
+
+            for pos in &number.symbols {
+                touching.entry(*pos).or_default().push(i);
+            }
+        }
+
+        Ok(Self {
+            grid,
+            numbers,
+            symbols,
+            touching,
+        })
+    }
+}
+
+impl Schematic {
+    #[must_use]
+    pub const fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    /// Every number, row by row.
+    #[must_use]
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    /// Every symbol, row by row.
+    #[must_use]
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    #[must_use]
+    pub fn symbol_at(&self, pos: Pos) -> Option<Symbol> {
+        self.grid
+            .get(pos)
+            .filter(|c| is_symbol(**c))
+            .map(|&c| Symbol { c, pos })
+    }
+
+    /// The numbers that touch at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().filter(|number| number.is_part())
+    }
+
+    /// The numbers that don't touch any symbol.
+    pub fn isolated_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().filter(|number| !number.is_part())
+    }
+
+    /// The numbers touching the symbol at `pos`, row by row.
+    pub fn parts_touching(&self, pos: Pos) -> impl Iterator<Item = &Number> {
+        self.touching
+            .get(&pos)
+            .into_iter()
+            .flatten()
+            .map(|i| &self.numbers[*i])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Locate;
+
+    use super::*;
+
+    #[test]
+    fn schematic() -> anyhow::Result<()> {
+        let schematic = include_str!("../example.txt").parse::<Schematic>()?;
+
+        assert_eq!(schematic.numbers().len(), 10);
+        assert_eq!(schematic.symbols().len(), 6);
+
+        assert_eq!(
+            schematic.numbers()[0],
+            Number {
+                value: 467,
+                row: 0,
+                columns: 0..3,
+                symbols: vec![Pos::new(3, 1)],
+            }
+        );
+        assert_eq!(
+            schematic.symbol_at(Pos::new(3, 1)),
+            Some(Symbol {
+                c: '*',
+                pos: Pos::new(3, 1)
+            })
+        );
+        assert_eq!(schematic.symbol_at(Pos::new(0, 0)), None);
+
+        assert_eq!(
+            schematic
+                .parts_touching(Pos::new(3, 1))
+                .map(|number| number.value)
+                .collect::<Vec<_>>(),
+            [467, 35]
+        );
+        assert_eq!(
+            schematic
+                .isolated_numbers()
+                .map(|number| number.value)
+                .collect::<Vec<_>>(),
+            [114, 58]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn large_numbers() -> anyhow::Result<()> {
+        let schematic = "4294967295*1\n".parse::<Schematic>()?;
+
+        assert_eq!(schematic.numbers()[0].value, u32::MAX);
+
+        let input = "...........\n.4294967296\n";
+        let error = input.parse::<Schematic>().locate(input).err();
+        let error = error.and_then(|e| e.downcast::<ParseError>().ok());
+
+        assert_eq!(
+            error.map(|e| (e.found, e.location.map(|l| (l.line, l.column)))),
+            Some(("4294967296".to_string(), Some((2, 2))))
+        );
+
+        Ok(())
+    }
+}
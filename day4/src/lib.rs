@@ -0,0 +1,114 @@
+#![warn(clippy::unwrap_used, clippy::pedantic, clippy::nursery)]
+#![allow(
+    clippy::module_inception,
+    clippy::module_name_repetitions,
+    clippy::missing_errors_doc,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+
+use std::{collections::HashSet, str::FromStr};
+
+use aoc_common::{parse, ParseError, Solution};
+
+struct Card {
+    pub wins: HashSet<u32>,
+    pub nums: HashSet<u32>,
+}
+
+impl Card {
+    pub fn win_count(&self) -> u32 {
+        self.wins.intersection(&self.nums).count() as u32
+    }
+
+    pub fn score(&self) -> u64 {
+        let win_count = self.win_count();
+
+        if win_count == 0 {
+            0
+        } else {
+            2u64.pow(win_count - 1)
+        }
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, s) = parse::split_once(s, ": ")?;
+        let (wins_s, nums_s) = parse::split_once(s, " | ")?;
+
+        Ok(Self {
+            wins: parse::numbers(wins_s)?.into_iter().collect(),
+            nums: parse::numbers(nums_s)?.into_iter().collect(),
+        })
+    }
+}
+
+fn process_cards(cards: &[Card]) -> Vec<u64> {
+    let mut counts = vec![1; cards.len()];
+
+    for (i, card) in cards.iter().enumerate() {
+        let this_count = counts[i];
+        let wins = card.win_count();
+
+        for count in &mut counts[i + 1..=(i + wins as usize).min(cards.len() - 1)] {
+            *count += this_count;
+        }
+    }
+
+    counts
+}
+
+pub struct Day {
+    cards: Vec<Card>,
+}
+
+impl Solution for Day {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            cards: input
+                .lines()
+                .map(Card::from_str)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<u64> {
+        Ok(self.cards.iter().map(Card::score).sum())
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        Ok(process_cards(&self.cards).into_iter().sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example.txt"))?;
+
+        assert_eq!(day.part1()?, 13);
+        assert_eq!(day.part2()?, 30);
+
+        Ok(())
+    }
+
+    #[test]
+    fn input() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../input.txt"))?;
+
+        assert_eq!(day.part1()?, 21088);
+        assert_eq!(day.part2()?, 6_874_754);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,201 @@
+#![warn(clippy::unwrap_used, clippy::pedantic, clippy::nursery)]
+#![allow(
+    clippy::module_inception,
+    clippy::module_name_repetitions,
+    clippy::missing_errors_doc,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss
+)]
+
+mod map;
+mod pipeline;
+mod validate;
+
+use std::ops::Range;
+
+use anyhow::Context;
+use aoc_common::{parse, ParseError, Solution};
+pub use map::{intersect_ranges, merge_ranges, Entry, Map};
+pub use pipeline::{Pipeline, Step};
+pub use validate::{Issue, IssueKind};
+
+fn parse_seeds(nums: &str) -> Result<Vec<u64>, ParseError> {
+    parse::numbers(nums)
+}
+
+/// Reads the seeds as pairs of a start and length.
+fn parse_seeds_2(nums: &str) -> Result<Vec<Range<u64>>, ParseError> {
+    nums.split_whitespace()
+        .collect::<Vec<_>>()
+        .chunks(2)
+        .map(|pair| match *pair {
+            [start_str, length_str] => {
+                let start: u64 = parse::number(start_str)?;
+                let length: u64 = parse::number(length_str)?;
+
+                start
+                    .checked_add(length)
+                    .map(|end| start..end)
+                    .ok_or_else(|| {
+                        ParseError::new(length_str, "a length keeping the range within 64 bits")
+                    })
+            }
+            // chunks never yields an empty slice, so this is the leftover seed
+            _ => Err(ParseError::new(
+                pair[0],
+                "a seed start paired with a length",
+            )),
+        })
+        .collect()
+}
+
+fn calc_lowest_location(
+    pipeline: &Pipeline,
+    seeds: impl Iterator<Item = u64>,
+) -> anyhow::Result<u64> {
+    let map = pipeline.compose("seed", "location")?.map;
+
+    seeds.map(|seed| map.lookup(seed)).min().context("No seeds")
+}
+
+pub struct Day {
+    seeds: Vec<u64>,
+    seed_ranges: Vec<Range<u64>>,
+    pipeline: Pipeline,
+}
+
+impl Day {
+    #[must_use]
+    pub const fn pipeline(&self) -> &Pipeline {
+        &self.pipeline
+    }
+
+    /// The seeds as listed, for part 1.
+    #[must_use]
+    pub fn seeds(&self) -> &[u64] {
+        &self.seeds
+    }
+
+    /// The seeds read as ranges, for part 2, sorted and merged.
+    #[must_use]
+    pub fn seed_ranges(&self) -> &[Range<u64>] {
+        &self.seed_ranges
+    }
+}
+
+impl Solution for Day {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        let (seeds, maps) = parse::split_once(input, "\n\n")?;
+        let pipeline = maps.parse::<Pipeline>()?;
+        let nums = parse::strip_prefix(seeds, "seeds:")?;
+
+        Ok(Self {
+            seeds: parse_seeds(nums)?,
+            seed_ranges: merge_ranges(parse_seeds_2(nums)?),
+            pipeline: pipeline.starting_at_line(seeds.matches('\n').count() + 3),
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<u64> {
+        calc_lowest_location(&self.pipeline, self.seeds.iter().copied())
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        self.pipeline
+            .convert_ranges("seed", "location", self.seed_ranges())?
+            .into_iter()
+            .map(|locations| locations.start)
+            .min()
+            .context("No seeds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Locate;
+
+    use super::*;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example.txt"))?;
+
+        assert_eq!(day.part1()?, 35);
+        assert_eq!(day.part2()?, 46);
+
+        Ok(())
+    }
+
+    #[test]
+    fn input() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../input.txt"))?;
+
+        assert_eq!(day.part1()?, 525_792_406);
+        assert_eq!(day.part2()?, 79_004_094);
+
+        Ok(())
+    }
+
+    // No seeds should land in any location below the lowest, and some should land on it
+    fn check_lowest(input: &str) -> anyhow::Result<()> {
+        let day = Day::parse(input)?;
+        let seeds_below = |location| {
+            let locations = 0..location;
+
+            day.pipeline()
+                .invert_ranges("seed", "location", std::slice::from_ref(&locations))
+        };
+
+        let lowest = day.part1()?;
+        let listed_below = |location| -> anyhow::Result<bool> {
+            let seeds = seeds_below(location)?;
+
+            Ok(day
+                .seeds()
+                .iter()
+                .any(|seed| seeds.iter().any(|range| range.contains(seed))))
+        };
+
+        assert!(!listed_below(lowest)?);
+        assert!(listed_below(lowest + 1)?);
+
+        let lowest = day.part2()?;
+
+        assert!(intersect_ranges(&seeds_below(lowest)?, day.seed_ranges()).is_empty());
+        assert!(!intersect_ranges(&seeds_below(lowest + 1)?, day.seed_ranges()).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn inverse_matches_lowest() -> anyhow::Result<()> {
+        check_lowest(include_str!("../example.txt"))?;
+        check_lowest(include_str!("../input.txt"))
+    }
+
+    fn seed_error(seeds: &str) -> Option<(String, usize)> {
+        let input = format!("seeds: {seeds}\n\nseed-to-location map:\n0 0 1\n");
+        let error = Day::parse(&input).locate(&input).err()?;
+        let error = error.downcast::<ParseError>().ok()?;
+
+        Some((error.expected, error.location?.column))
+    }
+
+    #[test]
+    fn seed_ranges() {
+        assert_eq!(
+            seed_error("1 2 3"),
+            Some(("a seed start paired with a length".to_string(), 12))
+        );
+        assert_eq!(
+            seed_error("18446744073709551610 10"),
+            Some(("a length keeping the range within 64 bits".to_string(), 29))
+        );
+        assert_eq!(seed_error("18446744073709551610 5"), None);
+    }
+}
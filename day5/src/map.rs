@@ -0,0 +1,439 @@
+use std::{fmt::Display, ops::Range, str::FromStr};
+
+use aoc_common::{parse, ParseError};
+use itertools::Itertools;
+
+/// One line of a map in the almanac.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub destination: u64,
+    pub source: u64,
+    pub length: u64,
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (destination, s) = parse::split_once(s, " ")?;
+        let (source, length) = parse::split_once(s, " ")?;
+
+        Ok(Self {
+            destination: parse::number(destination)?,
+            source: parse::number(source)?,
+            length: parse::number(length)?,
+        })
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.destination, self.source, self.length)
+    }
+}
+
+impl Entry {
+    /// The values mapped, cut short if they don't fit.
+    #[must_use]
+    pub const fn sources(self) -> Range<u64> {
+        self.source..self.source.saturating_add(self.length)
+    }
+
+    /// The values mapped to, cut short if they don't fit.
+    #[must_use]
+    pub const fn destinations(self) -> Range<u64> {
+        self.destination..self.destination.saturating_add(self.length)
+    }
+
+    /// What is added to each value.
+    #[must_use]
+    pub fn offset(self) -> i128 {
+        i128::from(self.destination) - i128::from(self.source)
+    }
+
+    /// Whether any of the values this maps from or to don't fit in a `u64`.
+    #[must_use]
+    pub const fn overflows(self) -> bool {
+        self.source.checked_add(self.length).is_none()
+            || self.destination.checked_add(self.length).is_none()
+    }
+}
+
+/// A piecewise function adding an offset to the values in each of its ranges,
+/// leaving any other value as it is.
+///
+/// No `Range<u64>` can hold `u64::MAX`, so that value always maps to itself.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Map {
+    // Sorted and disjoint, with neighbours sharing an offset merged and no zero offsets.
+    // Offsets are i128 so any difference between two u64s fits, including composed ones
+    ranges: Vec<(Range<u64>, i128)>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = s
+            .lines()
+            .map(Entry::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::from_entries(&entries))
+    }
+}
+
+impl Display for Map {
+    /// Writes the map as almanac lines, the destination start, source start and length.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for entry in self.entries() {
+            writeln!(f, "{entry}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn apply_offset(input: u64, offset: i128) -> u64 {
+    (i128::from(input) + offset).clamp(0, i128::from(u64::MAX)) as u64
+}
+
+/// Sorts `ranges`, merging any that overlap or touch, and dropping empty ones.
+#[must_use]
+pub fn merge_ranges(mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    ranges.retain(|range| !range.is_empty());
+    ranges.sort_unstable_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<u64>> = vec![];
+
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+/// The parts of `a` that are also in `b`, both sorted and disjoint like [`merge_ranges`] returns.
+#[must_use]
+pub fn intersect_ranges(a: &[Range<u64>], b: &[Range<u64>]) -> Vec<Range<u64>> {
+    a.iter()
+        .cartesian_product(b)
+        .map(|(a, b)| a.start.max(b.start)..a.end.min(b.end))
+        .filter(|range| !range.is_empty())
+        .collect()
+}
+
+/// The values in none of `ranges`, which must be sorted and disjoint, up to but not including `u64::MAX`.
+#[must_use]
+pub fn complement_ranges(ranges: &[Range<u64>]) -> Vec<Range<u64>> {
+    std::iter::once(0)
+        .chain(ranges.iter().map(|range| range.end))
+        .zip(ranges.iter().map(|range| range.start).chain([u64::MAX]))
+        .map(|(start, end)| start..end)
+        .filter(|range| !range.is_empty())
+        .collect()
+}
+
+impl Map {
+    /// Builds a map from possibly overlapping ranges, where the first range containing a value wins.
+    #[must_use]
+    pub fn new(ranges: &[(Range<u64>, i128)]) -> Self {
+        let bounds = ranges
+            .iter()
+            .flat_map(|(range, _)| [range.start, range.end])
+            .sorted_unstable()
+            .dedup();
+
+        let mut map = Self::default();
+
+        for (start, end) in bounds.tuple_windows() {
+            if let Some((_, offset)) = ranges.iter().find(|(range, _)| range.contains(&start)) {
+                map.push(start..end, *offset);
+            }
+        }
+
+        map
+    }
+
+    /// Builds a map from almanac lines, where the first line containing a value wins.
+    #[must_use]
+    pub fn from_entries(entries: &[Entry]) -> Self {
+        Self::new(
+            &entries
+                .iter()
+                .map(|entry| (entry.sources(), entry.offset()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// The almanac lines for the map's ranges.
+    #[must_use]
+    pub fn entries(&self) -> Vec<Entry> {
+        self.ranges
+            .iter()
+            .map(|(range, offset)| Entry {
+                destination: apply_offset(range.start, *offset),
+                source: range.start,
+                length: range.end - range.start,
+            })
+            .collect()
+    }
+
+    // Adds a range after all the others, merging it into the last if it continues it
+    fn push(&mut self, range: Range<u64>, offset: i128) {
+        if offset == 0 || range.is_empty() {
+            return;
+        }
+
+        match self.ranges.last_mut() {
+            Some((last, last_offset)) if (last.end, *last_offset) == (range.start, offset) => {
+                last.end = range.end;
+            }
+            _ => self.ranges.push((range, offset)),
+        }
+    }
+
+    /// The ranges that are offset, sorted and disjoint.
+    #[must_use]
+    pub fn ranges(&self) -> &[(Range<u64>, i128)] {
+        &self.ranges
+    }
+
+    /// Every value but `u64::MAX` split into sorted ranges, with the offset of each, including the unmapped gaps.
+    fn pieces(&self) -> impl Iterator<Item = (Range<u64>, i128)> + '_ {
+        let ends = self.ranges.iter().map(|(range, _)| range.end);
+
+        std::iter::once(0)
+            .chain(ends)
+            .zip(self.ranges.iter().map(Some).chain([None]))
+            .flat_map(|(gap_start, range)| {
+                let gap_end = range.map_or(u64::MAX, |(range, _)| range.start);
+
+                std::iter::once((gap_start..gap_end, 0)).chain(range.cloned())
+            })
+            .filter(|(range, _)| !range.is_empty())
+    }
+
+    #[must_use]
+    pub fn lookup(&self, input: u64) -> u64 {
+        let i = self.ranges.partition_point(|(range, _)| range.end <= input);
+
+        match self.ranges.get(i) {
+            Some((range, offset)) if range.contains(&input) => apply_offset(input, *offset),
+            _ => input,
+        }
+    }
+
+    /// Looks up every value in `input` at once,
+    /// splitting it wherever it crosses the edge of one of the map's ranges.
+    #[must_use]
+    pub fn lookup_range(&self, input: Range<u64>) -> Vec<Range<u64>> {
+        self.pieces()
+            .filter_map(|(range, offset)| {
+                let start = input.start.max(range.start);
+                let end = input.end.min(range.end);
+
+                (start < end).then(|| apply_offset(start, offset)..apply_offset(end, offset))
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn lookup_ranges(&self, inputs: &[Range<u64>]) -> Vec<Range<u64>> {
+        inputs
+            .iter()
+            .filter(|input| !input.is_empty())
+            .flat_map(|input| self.lookup_range(input.clone()))
+            .collect()
+    }
+
+    /// Every value that maps to `output`, in order.
+    #[must_use]
+    pub fn preimage(&self, output: u64) -> Vec<u64> {
+        self.pieces()
+            .map(|(range, offset)| (range, apply_offset(output, -offset)))
+            .filter(|(range, input)| range.contains(input) && self.lookup(*input) == output)
+            .map(|(_, input)| input)
+            .chain((output == u64::MAX).then_some(u64::MAX))
+            .collect()
+    }
+
+    /// The ranges of values that map into `output`, sorted and merged.
+    #[must_use]
+    pub fn preimage_range(&self, output: Range<u64>) -> Vec<Range<u64>> {
+        merge_ranges(
+            self.pieces()
+                .filter_map(|(range, offset)| {
+                    let image = apply_offset(range.start, offset)..apply_offset(range.end, offset);
+                    let start = output.start.max(image.start);
+                    let end = output.end.min(image.end);
+
+                    (start < end).then(|| apply_offset(start, -offset)..apply_offset(end, -offset))
+                })
+                .collect(),
+        )
+    }
+
+    #[must_use]
+    pub fn preimage_ranges(&self, outputs: &[Range<u64>]) -> Vec<Range<u64>> {
+        merge_ranges(
+            outputs
+                .iter()
+                .flat_map(|output| self.preimage_range(output.clone()))
+                .collect(),
+        )
+    }
+
+    /// A single map doing the same as looking up in this map, then in `next`.
+    #[must_use]
+    pub fn compose(&self, next: &Self) -> Self {
+        let mut map = Self::default();
+
+        for (range, offset) in self.pieces() {
+            let image = apply_offset(range.start, offset)..apply_offset(range.end, offset);
+
+            // Split the range wherever its image crosses one of next's breakpoints
+            for (next_range, next_offset) in next.pieces() {
+                let start = image.start.max(next_range.start);
+                let end = image.end.min(next_range.end);
+
+                if start < end {
+                    map.push(
+                        apply_offset(start, -offset)..apply_offset(end, -offset),
+                        offset + next_offset,
+                    );
+                }
+            }
+        }
+
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlapping() {
+        let map = Map::new(&[(10..20, 5), (15..30, -5), (30..40, -5), (0..5, 0)]);
+
+        assert_eq!(map.ranges(), [(10..20, 5), (20..40, -5)]);
+        assert_eq!(map.lookup(15), 20);
+        assert_eq!(map.lookup(25), 20);
+        assert_eq!(map.lookup(40), 40);
+    }
+
+    #[test]
+    fn lookup_range() -> anyhow::Result<()> {
+        let map = Map::from_str("50 98 2\n52 50 48")?;
+
+        let mut ranges = map.lookup_range(40..100);
+        ranges.sort_by_key(|range| range.start);
+        assert_eq!(ranges, [40..50, 50..52, 52..100]);
+
+        assert_eq!(map.lookup_range(0..10), vec![0..10]);
+        assert_eq!(map.lookup_range(98..99), vec![50..51]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn compose() -> anyhow::Result<()> {
+        let first = Map::from_str("50 98 2\n52 50 48")?;
+        let second = Map::from_str("0 15 37\n37 52 2\n39 0 15")?;
+        let composed = first.compose(&second);
+
+        for seed in 0..120 {
+            assert_eq!(composed.lookup(seed), second.lookup(first.lookup(seed)));
+        }
+
+        assert_eq!(
+            composed.to_string(),
+            "39 0 15\n0 15 35\n37 50 2\n54 52 46\n35 98 2\n"
+        );
+        assert_eq!(first.compose(&Map::default()), first);
+
+        Ok(())
+    }
+
+    #[test]
+    fn offsets_past_i64() -> anyhow::Result<()> {
+        let map = Map::from_str(&format!("{} 0 1\n0 {} 1", u64::MAX, u64::MAX - 1))?;
+
+        assert_eq!(map.lookup(0), u64::MAX);
+        assert_eq!(map.lookup(u64::MAX - 1), 0);
+        assert_eq!(map.preimage(u64::MAX), [0, u64::MAX]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn compose_past_i64() -> anyhow::Result<()> {
+        let first = Map::from_str(&format!("{} 0 1", i64::MAX))?;
+        let second = Map::from_str(&format!("{} {} 1", u64::MAX - 1, i64::MAX))?;
+        let composed = first.compose(&second);
+
+        assert_eq!(composed.lookup(0), u64::MAX - 1);
+        assert_eq!(composed.lookup(1), 1);
+        assert_eq!(
+            composed.to_string(),
+            format!("{} 0 1\n{} {} 1\n", u64::MAX - 1, u64::MAX - 1, i64::MAX)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn preimage() -> anyhow::Result<()> {
+        // 50..52 is reached both from 50..52 and from 98..100
+        let map = Map::from_str("50 98 2\n60 60 1")?;
+
+        assert_eq!(map.preimage(50), [50, 98]);
+        assert_eq!(map.preimage(98), []);
+        assert_eq!(map.preimage(7), [7]);
+        assert_eq!(map.preimage_range(50..52), [50..52, 98..100]);
+        assert_eq!(map.preimage_range(40..100), vec![40..100]);
+        assert_eq!(map.preimage_range(98..100), []);
+        assert_eq!(map.preimage_ranges(&[0..10, 5..20]), vec![0..20]);
+
+        for output in 0..120 {
+            let inputs = map
+                .preimage_range(output..output + 1)
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+
+            assert_eq!(inputs, map.preimage(output));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn last_value() -> anyhow::Result<()> {
+        let map = Map::from_str(&format!("{} 5 1", u64::MAX))?;
+
+        assert_eq!(map.lookup(u64::MAX), u64::MAX);
+        assert_eq!(map.preimage(u64::MAX), [5, u64::MAX]);
+        assert_eq!(Map::default().preimage(u64::MAX), [u64::MAX]);
+        assert_eq!(complement_ranges(&[]), vec![0..u64::MAX]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(
+            merge_ranges(vec![5..8, 0..2, 2..3, 6..10, 4..4]),
+            [0..3, 5..10]
+        );
+        assert_eq!(
+            intersect_ranges(&[0..5, 8..12], &[3..9, 11..20]),
+            [3..5, 8..9, 11..12]
+        );
+    }
+}
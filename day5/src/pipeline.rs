@@ -0,0 +1,403 @@
+use std::{fmt::Display, ops::Range, str::FromStr};
+
+use anyhow::{bail, Context};
+use aoc_common::{parse, ParseError};
+use itertools::Itertools;
+
+use crate::map::{merge_ranges, Entry, Map};
+
+/// One map in the almanac, converting from one category to the next.
+pub struct Step {
+    pub from: String,
+    pub to: String,
+    pub map: Map,
+    /// The lines the map was built from
+    pub entries: Vec<Entry>,
+    /// The header's line in the input, starting at 1, or 0 if the step wasn't parsed
+    pub line: usize,
+}
+
+impl Step {
+    /// Parses a map paragraph, its header and ranges, with the header on `line`.
+    fn parse(paragraph: &str, line: usize) -> Result<Self, ParseError> {
+        // Keep the body as a slice of the input, even when empty, so errors can be located
+        let (header, body) = paragraph
+            .split_once('\n')
+            .unwrap_or_else(|| (paragraph, &paragraph[paragraph.len()..]));
+
+        let names = parse::strip_suffix(header, " map:")?;
+        let (from, to) = parse::split_once(names, "-to-")?;
+
+        let entries = body
+            .lines()
+            .map(Entry::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            from: from.to_string(),
+            to: to.to_string(),
+            map: Map::from_entries(&entries),
+            entries,
+            line,
+        })
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}-to-{} map:", self.from, self.to)?;
+        write!(f, "{}", self.map)
+    }
+}
+
+/// The almanac's maps, ordered into a chain where each converts to the category the next converts from.
+pub struct Pipeline {
+    steps: Vec<Step>,
+}
+
+impl FromStr for Pipeline {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let paragraphs = s
+            .split("\n\n")
+            .map(str::trim_end)
+            .filter(|paragraph| !paragraph.is_empty())
+            .collect::<Vec<_>>();
+
+        let steps = paragraphs
+            .iter()
+            .map(|paragraph| {
+                let offset = paragraph.as_ptr() as usize - s.as_ptr() as usize;
+
+                Step::parse(paragraph, s[..offset].matches('\n').count() + 1)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // The header of each step, for errors about how the maps fit together
+        let header = |i: usize| paragraphs[i].lines().next().unwrap_or(paragraphs[i]);
+
+        for (i, step) in steps.iter().enumerate() {
+            if steps[..i].iter().any(|other| other.from == step.from) {
+                return Err(ParseError::new(
+                    header(i),
+                    format!("only one map from {}", step.from),
+                ));
+            }
+
+            if steps[..i].iter().any(|other| other.to == step.to) {
+                return Err(ParseError::new(
+                    header(i),
+                    format!("only one map to {}", step.to),
+                ));
+            }
+        }
+
+        if steps.is_empty() {
+            return Ok(Self { steps });
+        }
+
+        // The chain starts from a category that isn't converted to
+        let start = steps
+            .iter()
+            .position(|step| steps.iter().all(|other| other.to != step.from))
+            .ok_or_else(|| ParseError::new(header(0), "maps that don't loop"))?;
+
+        let mut order = vec![start];
+
+        while let Some(next) = steps
+            .iter()
+            .position(|step| step.from == steps[order[order.len() - 1]].to)
+        {
+            order.push(next);
+        }
+
+        if let Some(unused) = (0..steps.len()).find(|i| !order.contains(i)) {
+            return Err(ParseError::new(
+                header(unused),
+                format!(
+                    "a map joining the chain from {} to {}",
+                    steps[start].from,
+                    steps[order[order.len() - 1]].to
+                ),
+            ));
+        }
+
+        let mut ranks = vec![0; steps.len()];
+
+        for (rank, &i) in order.iter().enumerate() {
+            ranks[i] = rank;
+        }
+
+        let mut ranked = ranks.into_iter().zip(steps).collect::<Vec<_>>();
+        ranked.sort_by_key(|(rank, _)| *rank);
+
+        Ok(Self {
+            steps: ranked.into_iter().map(|(_, step)| step).collect(),
+        })
+    }
+}
+
+impl Pipeline {
+    /// Moves every step's line down, for maps that start on `line` of a larger input.
+    #[must_use]
+    pub fn starting_at_line(mut self, line: usize) -> Self {
+        for step in &mut self.steps {
+            step.line += line - 1;
+        }
+
+        self
+    }
+
+    #[must_use]
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Every category, in the order they are converted through.
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        self.steps
+            .first()
+            .map(|step| step.from.as_str())
+            .into_iter()
+            .chain(self.steps.iter().map(|step| step.to.as_str()))
+    }
+
+    /// The steps converting from `from` to `to`, empty if they're the same category.
+    pub fn path(&self, from: &str, to: &str) -> anyhow::Result<&[Step]> {
+        let position = |category| {
+            self.categories()
+                .position(|other| other == category)
+                .with_context(|| format!("There are no maps for {category}"))
+        };
+
+        let (start, end) = (position(from)?, position(to)?);
+
+        if end < start {
+            bail!("{to} comes before {from}, so can't be converted to");
+        }
+
+        Ok(&self.steps[start..end])
+    }
+
+    /// Every `from` value that converts to `value` in `to`.
+    pub fn invert(&self, from: &str, to: &str, value: u64) -> anyhow::Result<Vec<u64>> {
+        let mut values = vec![value];
+
+        for step in self.path(from, to)?.iter().rev() {
+            values = values
+                .into_iter()
+                .flat_map(|value| step.map.preimage(value))
+                .sorted_unstable()
+                .dedup()
+                .collect();
+        }
+
+        Ok(values)
+    }
+
+    /// The ranges of `from` values that convert to any of `values` in `to`, sorted and merged.
+    pub fn invert_ranges(
+        &self,
+        from: &str,
+        to: &str,
+        values: &[Range<u64>],
+    ) -> anyhow::Result<Vec<Range<u64>>> {
+        Ok(self
+            .path(from, to)?
+            .iter()
+            .rev()
+            .fold(merge_ranges(values.to_vec()), |ranges, step| {
+                step.map.preimage_ranges(&ranges)
+            }))
+    }
+
+    /// A single step converting from `from` to `to`, composing all the maps between them.
+    pub fn compose(&self, from: &str, to: &str) -> anyhow::Result<Step> {
+        let map = self
+            .path(from, to)?
+            .iter()
+            .fold(Map::default(), |map, step| map.compose(&step.map));
+
+        Ok(Step {
+            from: from.to_string(),
+            to: to.to_string(),
+            entries: map.entries(),
+            map,
+            line: 0,
+        })
+    }
+
+    pub fn convert(&self, from: &str, to: &str, value: u64) -> anyhow::Result<u64> {
+        Ok(self
+            .path(from, to)?
+            .iter()
+            .fold(value, |value, step| step.map.lookup(value)))
+    }
+
+    /// Converts every value in `values` at once,
+    /// returning the ranges of values they end up at.
+    pub fn convert_ranges(
+        &self,
+        from: &str,
+        to: &str,
+        values: &[Range<u64>],
+    ) -> anyhow::Result<Vec<Range<u64>>> {
+        Ok(self
+            .path(from, to)?
+            .iter()
+            .fold(values.to_vec(), |ranges, step| {
+                step.map.lookup_ranges(&ranges)
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Locate;
+
+    use super::*;
+
+    fn example() -> anyhow::Result<Pipeline> {
+        let (_, maps) = include_str!("../example.txt")
+            .split_once("\n\n")
+            .context("No maps")?;
+
+        Ok(maps.parse()?)
+    }
+
+    #[test]
+    fn categories() -> anyhow::Result<()> {
+        let pipeline = example()?;
+
+        assert_eq!(
+            pipeline.categories().collect::<Vec<_>>(),
+            [
+                "seed",
+                "soil",
+                "fertilizer",
+                "water",
+                "light",
+                "temperature",
+                "humidity",
+                "location"
+            ]
+        );
+        assert_eq!(pipeline.convert("seed", "soil", 79)?, 81);
+        assert_eq!(pipeline.convert("soil", "humidity", 81)?, 78);
+        assert_eq!(pipeline.convert("seed", "location", 79)?, 82);
+        assert_eq!(pipeline.convert("water", "water", 5)?, 5);
+        assert!(pipeline.convert("location", "seed", 5).is_err());
+        assert!(pipeline.convert("seed", "sunlight", 5).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn any_order() -> anyhow::Result<()> {
+        let pipeline = "b-to-c map:\n0 0 5\n\na-to-b map:\n10 0 5\n".parse::<Pipeline>()?;
+
+        assert_eq!(pipeline.categories().collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(pipeline.convert("a", "c", 3)?, 13);
+
+        Ok(())
+    }
+
+    fn error(s: &str) -> Option<(String, usize)> {
+        let error = s.parse::<Pipeline>().locate(s).err()?;
+
+        Some((error.expected, error.location?.line))
+    }
+
+    #[test]
+    fn broken_chains() {
+        assert_eq!(
+            error("a-to-b map:\n\na-to-c map:\n"),
+            Some(("only one map from a".to_string(), 3))
+        );
+        assert_eq!(
+            error("a-to-c map:\n\nb-to-c map:\n"),
+            Some(("only one map to c".to_string(), 3))
+        );
+        assert_eq!(
+            error("a-to-b map:\n\nc-to-d map:\n"),
+            Some(("a map joining the chain from a to b".to_string(), 3))
+        );
+        assert_eq!(
+            error("a-to-b map:\n\nb-to-a map:\n"),
+            Some(("maps that don't loop".to_string(), 1))
+        );
+        assert_eq!(
+            error("a-to-b map:\n\nb to c map:\n"),
+            Some(("\"-to-\"".to_string(), 3))
+        );
+    }
+
+    #[test]
+    fn compose() -> anyhow::Result<()> {
+        let pipeline = example()?;
+        let step = pipeline.compose("seed", "location")?;
+
+        for seed in 0..120 {
+            assert_eq!(
+                step.map.lookup(seed),
+                pipeline.convert("seed", "location", seed)?
+            );
+        }
+
+        assert!(step.to_string().starts_with("seed-to-location map:\n"));
+        assert_eq!(pipeline.compose("soil", "soil")?.map, Map::default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn invert() -> anyhow::Result<()> {
+        let pipeline = example()?;
+
+        assert_eq!(pipeline.invert("seed", "location", 82)?, [79]);
+        assert_eq!(pipeline.invert("soil", "humidity", 78)?, [81]);
+        assert_eq!(pipeline.invert("seed", "seed", 5)?, [5]);
+
+        let locations = 0..100;
+        let seeds = pipeline.invert_ranges("seed", "location", std::slice::from_ref(&locations))?;
+
+        for seed in 0..200 {
+            let location = pipeline.convert("seed", "location", seed)?;
+
+            assert_eq!(
+                seeds.iter().any(|range| range.contains(&seed)),
+                location < 100
+            );
+            assert!(pipeline
+                .invert("seed", "location", location)?
+                .contains(&seed));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_ranges() -> anyhow::Result<()> {
+        let pipeline = example()?;
+
+        for seeds in [79..93, 55..68, 0..100] {
+            let mut expected = seeds
+                .clone()
+                .map(|seed| pipeline.convert("seed", "location", seed))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            expected.sort_unstable();
+
+            let mut locations = pipeline
+                .convert_ranges("seed", "location", &[seeds])?
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            locations.sort_unstable();
+
+            assert_eq!(locations, expected);
+        }
+
+        Ok(())
+    }
+}
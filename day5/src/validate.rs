@@ -0,0 +1,256 @@
+use std::{fmt::Display, ops::Range};
+
+use crate::{
+    map::{complement_ranges, intersect_ranges, merge_ranges, Entry},
+    Pipeline, Step,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IssueKind {
+    /// Two lines map some of the same values, so only the first applies to them
+    OverlappingSources,
+    /// Two lines map onto some of the same values
+    OverlappingDestinations,
+    /// A line maps onto values that aren't mapped, and so also map to themselves
+    NotInjective,
+    /// A line's values don't fit in a `u64`
+    Overflow,
+}
+
+/// A problem with some lines of a map.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Issue {
+    pub kind: IssueKind,
+    /// The map's header without " map:", e.g. `seed-to-soil`
+    pub map: String,
+    /// The offending lines, with their line in the input
+    pub lines: Vec<(usize, Entry)>,
+    /// The values affected
+    pub values: Range<u64>,
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values = &self.values;
+
+        write!(f, "{} map: ", self.map)?;
+
+        match self.kind {
+            IssueKind::OverlappingSources => write!(f, "source ranges overlap on {values:?}")?,
+            IssueKind::OverlappingDestinations => {
+                write!(f, "destination ranges overlap on {values:?}")?;
+            }
+            IssueKind::NotInjective => write!(
+                f,
+                "maps onto {values:?}, which is also left unmapped so maps to itself"
+            )?,
+            IssueKind::Overflow => write!(f, "{values:?} overflows 64 bits")?,
+        }
+
+        for (number, entry) in &self.lines {
+            write!(f, "\n    line {number}: {entry}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Step {
+    /// Every issue with the map's lines, by kind and then line.
+    #[must_use]
+    pub fn validate(&self) -> Vec<Issue> {
+        let issue = |kind, lines: &[(usize, Entry)], values| Issue {
+            kind,
+            map: format!("{}-to-{}", self.from, self.to),
+            lines: lines.to_vec(),
+            values,
+        };
+
+        let (overflowing, entries): (Vec<_>, Vec<_>) = self
+            .entries
+            .iter()
+            .copied()
+            .enumerate()
+            .map(|(i, entry)| (self.line + i + 1, entry))
+            .partition(|(_, entry)| entry.overflows());
+
+        let mut issues = overflowing
+            .iter()
+            .map(|&line| {
+                let (_, entry) = line;
+                let values = if entry.source.checked_add(entry.length).is_none() {
+                    entry.sources()
+                } else {
+                    entry.destinations()
+                };
+
+                issue(IssueKind::Overflow, &[line], values)
+            })
+            .collect::<Vec<_>>();
+
+        for (kind, range) in [
+            (
+                IssueKind::OverlappingSources,
+                Entry::sources as fn(Entry) -> Range<u64>,
+            ),
+            (IssueKind::OverlappingDestinations, Entry::destinations),
+        ] {
+            for (i, &a) in entries.iter().enumerate() {
+                for &b in &entries[i + 1..] {
+                    let (x, y) = (range(a.1), range(b.1));
+                    let overlap = x.start.max(y.start)..x.end.min(y.end);
+
+                    if !overlap.is_empty() {
+                        issues.push(issue(kind, &[a, b], overlap));
+                    }
+                }
+            }
+        }
+
+        let unmapped = complement_ranges(&merge_ranges(
+            entries.iter().map(|(_, entry)| entry.sources()).collect(),
+        ));
+
+        for (i, &line) in entries.iter().enumerate() {
+            let (_, entry) = line;
+
+            // Only the values not already mapped by an earlier line
+            let earlier = merge_ranges(entries[..i].iter().map(|(_, e)| e.sources()).collect());
+            let mapped = intersect_ranges(&[entry.sources()], &complement_ranges(&earlier))
+                .into_iter()
+                .map(|range| {
+                    entry.destination + (range.start - entry.source)
+                        ..entry.destination + (range.end - entry.source)
+                })
+                .collect::<Vec<_>>();
+
+            for values in intersect_ranges(&merge_ranges(mapped), &unmapped) {
+                issues.push(issue(IssueKind::NotInjective, &[line], values));
+            }
+        }
+
+        issues
+    }
+}
+
+impl Pipeline {
+    /// Every issue with every map, in the order they are converted through.
+    #[must_use]
+    pub fn validate(&self) -> Vec<Issue> {
+        self.steps().iter().flat_map(Step::validate).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+    use aoc_common::Solution;
+
+    use super::*;
+
+    // Each issue's kind, line numbers and values
+    type Summary = (IssueKind, Vec<usize>, Range<u64>);
+
+    fn issues(map: &str) -> anyhow::Result<Vec<Summary>> {
+        let pipeline = format!("a-to-b map:\n{map}").parse::<Pipeline>()?;
+
+        Ok(pipeline
+            .validate()
+            .into_iter()
+            .map(|issue| {
+                let lines = issue.lines.iter().map(|(number, _)| *number).collect();
+
+                (issue.kind, lines, issue.values)
+            })
+            .collect())
+    }
+
+    #[test]
+    fn valid() -> anyhow::Result<()> {
+        for input in [include_str!("../example.txt"), include_str!("../input.txt")] {
+            let (_, maps) = input.split_once("\n\n").context("No maps")?;
+
+            assert_eq!(maps.parse::<Pipeline>()?.validate(), []);
+        }
+
+        assert_eq!(issues("50 98 2\n52 50 48")?, []);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overlapping() -> anyhow::Result<()> {
+        assert_eq!(
+            issues("50 98 2\n52 50 48\n0 99 5")?,
+            [
+                (IssueKind::OverlappingSources, vec![2, 4], 99..100),
+                (IssueKind::NotInjective, vec![4], 1..5),
+            ]
+        );
+        assert_eq!(
+            issues("10 0 5\n12 5 5\n0 10 10")?,
+            [(IssueKind::OverlappingDestinations, vec![2, 3], 12..15)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn not_injective() -> anyhow::Result<()> {
+        assert_eq!(
+            issues("10 0 5\n5 10 5")?,
+            [(IssueKind::NotInjective, vec![3], 5..10)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflow() -> anyhow::Result<()> {
+        let issues = format!("a-to-b map:\n0 {} 2\n5 0 2", u64::MAX - 1)
+            .parse::<Pipeline>()?
+            .validate();
+
+        assert_eq!(
+            issues.iter().map(|issue| issue.kind).collect::<Vec<_>>(),
+            [IssueKind::Overflow, IssueKind::NotInjective]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            format!(
+                "a-to-b map: {:?} overflows 64 bits\n    line 2: 0 {} 2",
+                u64::MAX - 1..u64::MAX,
+                u64::MAX - 1
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn large_offsets() -> anyhow::Result<()> {
+        // Swapping the ends, so the offsets don't fit in an i64 but the values fit in a u64
+        assert_eq!(
+            issues(&format!("{} 0 1\n0 {} 1", u64::MAX - 1, u64::MAX - 1))?,
+            []
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn input_lines() -> anyhow::Result<()> {
+        let input = "seeds: 1 2\n\na-to-b map:\n0 0 5\n\nb-to-c map:\n10 0 5\n20 30 5\n";
+        let issues = crate::Day::parse(input)?.pipeline().validate();
+
+        assert_eq!(
+            issues.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [
+                "b-to-c map: maps onto 10..15, which is also left unmapped so maps to itself\n    line 7: 10 0 5",
+                "b-to-c map: maps onto 20..25, which is also left unmapped so maps to itself\n    line 8: 20 30 5",
+            ]
+        );
+
+        Ok(())
+    }
+}
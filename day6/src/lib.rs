@@ -0,0 +1,192 @@
+#![warn(clippy::unwrap_used, clippy::pedantic, clippy::nursery)]
+#![allow(
+    clippy::module_inception,
+    clippy::module_name_repetitions,
+    clippy::missing_errors_doc,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss
+)]
+
+use anyhow::Context;
+use aoc_common::{parse, ParseError, Solution};
+
+/// How many ways there are to beat `dist` in a race lasting `race_time`.
+///
+/// Holding the button for `b` wins when `(race_time - b) * b > dist`,
+/// or with `k = race_time - 2b`, when `k^2 < race_time^2 - 4 dist`,
+/// so this counts the `k` with the same parity as `race_time` below the square root.
+const fn num_of_wins(race_time: u64, dist: u64) -> u64 {
+    let (race_time, dist) = (race_time as u128, dist as u128);
+
+    // Neither overflows, as race_time^2 < 2^128 and 4 * dist < 2^66
+    let (squared, quadrupled) = (race_time * race_time, 4 * dist);
+
+    if squared <= quadrupled {
+        return 0;
+    }
+
+    // The largest k with k^2 < race_time^2 - 4 dist, at most race_time - 1
+    let max_k = (squared - quadrupled - 1).isqrt();
+
+    // -max_k..=max_k in steps of 2, one fewer if max_k has the wrong parity
+    (max_k + 1 - (max_k + race_time) % 2) as u64
+}
+
+/// Parses the numbers after `label`, and the digits they make with the spaces removed.
+fn parse_line(line: &str, label: &str) -> Result<(Vec<u64>, String), ParseError> {
+    let nums_str = parse::strip_prefix(line, label)?;
+    let nums = parse::numbers(nums_str)?;
+
+    let joined = nums_str.chars().filter(|c| !c.is_whitespace()).collect();
+
+    Ok((nums, joined))
+}
+
+pub struct Day {
+    races: Vec<(u64, u64)>,
+    // The digits of part 2's time and distance, which might not fit in a u64
+    long_race: (String, String),
+}
+
+impl Solution for Day {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        let (times_str, dists_str) = parse::split_once(input, "\n")?;
+        let dists_str = dists_str.trim_end();
+
+        let (times, time) = parse_line(times_str, "Time:")?;
+        let (dists, dist) = parse_line(dists_str, "Distance:")?;
+
+        if dists.len() != times.len() {
+            return Err(ParseError::new(dists_str, format!("{} distances", times.len())).into());
+        }
+
+        Ok(Self {
+            races: times.into_iter().zip(dists).collect(),
+            long_race: (time, dist),
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<u64> {
+        let mut wins = self
+            .races
+            .iter()
+            .map(|&(race_time, dist)| num_of_wins(race_time, dist));
+        let first = wins.next().context("No wins found")?;
+
+        wins.try_fold(first, u64::checked_mul)
+            .context("The product of the wins overflows 64 bits")
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        let (time, dist) = &self.long_race;
+        let number = |digits: &str, name| {
+            digits
+                .parse()
+                .with_context(|| format!("The joined {name} {digits} doesn't fit in 64 bits"))
+        };
+
+        Ok(num_of_wins(
+            number(time, "time")?,
+            number(dist, "distance")?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn brute_force(race_time: u64, dist: u64) -> u64 {
+        (0..=race_time)
+            .filter(|b| u128::from(race_time - b) * u128::from(*b) > u128::from(dist))
+            .count() as u64
+    }
+
+    // The distance travelled holding the button for `b`
+    fn travelled(race_time: u64, b: u64) -> u128 {
+        u128::from(race_time - b) * u128::from(b)
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(race_time in 0..2000_u64, dist in 0..1_000_000_u64) {
+            prop_assert_eq!(num_of_wins(race_time, dist), brute_force(race_time, dist));
+        }
+
+        #[test]
+        fn near_the_best(race_time in 0..2000_u64, below in 0..50_u64) {
+            let dist = travelled(race_time, race_time / 2).saturating_sub(u128::from(below)) as u64;
+
+            prop_assert_eq!(num_of_wins(race_time, dist), brute_force(race_time, dist));
+        }
+
+        // Too many to count, so check the wins are exactly the hold times between two bounds
+        #[test]
+        fn bounds(race_time: u64, dist: u64) {
+            let wins = num_of_wins(race_time, dist);
+
+            prop_assert!(wins <= race_time);
+
+            if wins == 0 {
+                prop_assert!(travelled(race_time, race_time / 2) <= u128::from(dist));
+            } else {
+                let first = (race_time - wins).div_ceil(2);
+
+                prop_assert_eq!(first + wins - 1, race_time - first);
+                prop_assert!(travelled(race_time, first) > u128::from(dist));
+                prop_assert!(first == 0 || travelled(race_time, first - 1) <= u128::from(dist));
+            }
+        }
+    }
+
+    #[test]
+    fn large_races() -> anyhow::Result<()> {
+        let day = Day::parse("Time: 18446744073709551615 2\nDistance: 0 0\n")?;
+
+        assert_eq!(day.part1()?, u64::MAX - 1);
+        assert!(day.part2().is_err());
+
+        let day = Day::parse("Time: 1844674407 3709551615\nDistance: 0 0\n")?;
+
+        assert_eq!(day.part2()?, u64::MAX - 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn extremes() {
+        assert_eq!(num_of_wins(u64::MAX, 0), u64::MAX - 1);
+        assert_eq!(num_of_wins(u64::MAX, u64::MAX), u64::MAX - 3);
+        assert_eq!(num_of_wins(0, 0), 0);
+        assert_eq!(num_of_wins(2, 0), 1);
+        assert_eq!(num_of_wins(3, 2), 0);
+        assert_eq!(num_of_wins(30, 200), 9);
+    }
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example.txt"))?;
+
+        assert_eq!(day.part1()?, 288);
+        assert_eq!(day.part2()?, 71503);
+
+        Ok(())
+    }
+
+    #[test]
+    fn input() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../input.txt"))?;
+
+        assert_eq!(day.part1()?, 1_660_968);
+        assert_eq!(day.part2()?, 26_499_773);
+
+        Ok(())
+    }
+}
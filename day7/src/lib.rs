@@ -2,6 +2,7 @@
 #![allow(
     clippy::module_inception,
     clippy::module_name_repetitions,
+    clippy::missing_errors_doc,
     clippy::cast_precision_loss,
     clippy::cast_possible_truncation,
     clippy::cast_sign_loss
@@ -9,43 +10,34 @@
 
 use std::{fmt::Display, str::FromStr};
 
-use anyhow::Context;
-
-const PART1: bool = false;
+use aoc_common::{parse, ParseError, Solution};
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 struct Card(u8);
 
-impl PartialOrd for Card {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for Card {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let process = |rank| {
-            if !PART1 && rank == 11 {
-                0
-            } else {
-                rank
-            }
-        };
-        process(self.0).cmp(&process(other.0))
+impl Card {
+    // With jokers, J is the weakest card for the 2nd ordering rule
+    pub const fn strength(self, jokers: bool) -> u8 {
+        if jokers && self.0 == 11 {
+            0
+        } else {
+            self.0
+        }
     }
 }
 
 impl FromStr for Card {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let n = match s.chars().next().context("Invalid input")? {
-            'A' => 14,
-            'K' => 13,
-            'Q' => 12,
-            'J' => 11,
-            'T' => 10,
-            c => c.to_digit(10).context("Invalid input")? as u8,
+        let n = match s {
+            "A" => 14,
+            "K" => 13,
+            "Q" => 12,
+            "J" => 11,
+            "T" => 10,
+            "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9" => parse::number(s)?,
+            _ => return Err(ParseError::new(s, "a card")),
         };
 
         Ok(Self(n))
@@ -70,31 +62,48 @@ impl Display for Card {
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 struct Hand {
     // This is the hand with J swapped for the best option
-    // This will be same same as original if PART1
+    // This will be same same as original without jokers
     wild: [Card; 5],
     // This is with the Js still there
     // Used for the 2nd ordering rule
     original: [Card; 5],
+    jokers: bool,
 }
 
 impl FromStr for Hand {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let hand = [
-            s[0..=0].parse().context("Invalid input")?,
-            s[1..=1].parse().context("Invalid input")?,
-            s[2..=2].parse().context("Invalid input")?,
-            s[3..=3].parse().context("Invalid input")?,
-            s[4..=4].parse().context("Invalid input")?,
-        ];
+        let hand: [Card; 5] = s
+            .char_indices()
+            .map(|(i, c)| s[i..i + c.len_utf8()].parse())
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            .map_err(|_| ParseError::new(s, "a hand of 5 cards"))?;
+
         Ok(Self {
             wild: hand,
             original: hand,
+            jokers: false,
         })
     }
 }
 
+impl Hand {
+    pub fn with_jokers(mut self) -> Self {
+        let most_freq = most_freq(self);
+
+        for card in &mut self.wild {
+            if *card == Card(11) {
+                *card = most_freq;
+            }
+        }
+
+        self.jokers = true;
+        self
+    }
+}
+
 impl PartialOrd<Self> for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
@@ -107,7 +116,9 @@ impl Ord for Hand {
 
         for i in 0..5 {
             if ord == std::cmp::Ordering::Equal {
-                ord = self.original[i].cmp(&other.original[i]);
+                ord = self.original[i]
+                    .strength(self.jokers)
+                    .cmp(&other.original[i].strength(other.jokers));
             } else {
                 break;
             }
@@ -191,47 +202,81 @@ fn most_freq(hand: Hand) -> Card {
         .unwrap_or(Card(13))
 }
 
-fn main() -> anyhow::Result<()> {
-    let input = std::fs::read_to_string("input.txt")?;
+pub struct Day {
+    hands: Vec<(Hand, u32)>,
+}
 
-    let mut hands = input
-        .lines()
-        .filter_map(|line| line.split_once(' '))
-        .filter_map(|(hand, bid)| Hand::from_str(hand).ok().map(|hand| (hand, bid)))
-        .filter_map(|(hand, bid)| u32::from_str(bid).ok().map(|bid| (hand, bid)))
-        .collect::<Vec<_>>();
+impl Day {
+    fn total_winnings(&self, jokers: bool) -> u32 {
+        let mut hands = self
+            .hands
+            .iter()
+            .map(|&(hand, bid)| {
+                if jokers {
+                    (hand.with_jokers(), bid)
+                } else {
+                    (hand, bid)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        hands.sort_by_key(|(hand, _)| *hand);
+
+        hands
+            .into_iter()
+            .enumerate()
+            .map(|(rank, (_, bid))| (rank as u32 + 1) * bid)
+            .sum()
+    }
+}
 
-    if !PART1 {
-        for (hand, _) in &mut hands {
-            let most_freq = most_freq(*hand);
+impl Solution for Day {
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-            if hand.wild[0] == Card(11) {
-                hand.wild[0] = most_freq;
-            }
-            if hand.wild[1] == Card(11) {
-                hand.wild[1] = most_freq;
-            }
-            if hand.wild[2] == Card(11) {
-                hand.wild[2] = most_freq;
-            }
-            if hand.wild[3] == Card(11) {
-                hand.wild[3] = most_freq;
-            }
-            if hand.wild[4] == Card(11) {
-                hand.wild[4] = most_freq;
-            }
-        }
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            hands: input
+                .lines()
+                .map(|line| -> Result<_, ParseError> {
+                    let (hand, bid) = parse::split_once(line, " ")?;
+
+                    Ok((hand.parse()?, parse::number(bid)?))
+                })
+                .collect::<Result<_, _>>()?,
+        })
     }
 
-    hands.sort_by_key(|(hand, _)| *hand);
+    fn part1(&self) -> anyhow::Result<u32> {
+        Ok(self.total_winnings(false))
+    }
 
-    let winnings: u32 = hands
-        .into_iter()
-        .enumerate()
-        .map(|(rank, (_, bid))| (rank as u32 + 1) * bid)
-        .sum();
+    fn part2(&self) -> anyhow::Result<u32> {
+        Ok(self.total_winnings(true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example.txt"))?;
+
+        assert_eq!(day.part1()?, 6440);
+        assert_eq!(day.part2()?, 5905);
+
+        Ok(())
+    }
+
+    #[test]
+    fn input() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../input.txt"))?;
 
-    println!("{winnings}");
+        assert_eq!(day.part1()?, 252_656_917);
+        assert_eq!(day.part2()?, 253_499_763);
 
-    Ok(())
+        Ok(())
+    }
 }
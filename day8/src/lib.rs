@@ -0,0 +1,286 @@
+#![warn(clippy::unwrap_used, clippy::pedantic, clippy::nursery)]
+#![allow(
+    clippy::module_inception,
+    clippy::module_name_repetitions,
+    clippy::missing_errors_doc,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use anyhow::Context;
+use aoc_common::{parse, ParseError, Solution};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+struct Node {
+    id: [u8; 3],
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(&self.id))
+    }
+}
+
+impl Node {
+    pub fn new(id: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            id: id
+                .as_bytes()
+                .try_into()
+                .map_err(|_| ParseError::new(id, "a 3 letter node"))?,
+        })
+    }
+
+    pub const fn start() -> Self {
+        Self { id: *b"AAA" }
+    }
+
+    pub fn is_end(self) -> bool {
+        self.id == *b"ZZZ"
+    }
+
+    pub const fn is_start_2(self) -> bool {
+        self.id[2] == b'A'
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => Err(ParseError::new(s, "L or R")),
+        }
+    }
+}
+
+type MapInner = HashMap<Node, (Node, Node)>;
+
+struct Map {
+    map: MapInner,
+}
+
+impl Map {
+    pub const fn from_inner(map: MapInner) -> Self {
+        Self { map }
+    }
+
+    pub fn lookup(&self, node: Node, dir: Direction) -> anyhow::Result<Node> {
+        let (left, right) = self
+            .map
+            .get(&node)
+            .with_context(|| format!("No node {node}"))?;
+
+        Ok(match dir {
+            Direction::Left => *left,
+            Direction::Right => *right,
+        })
+    }
+}
+
+fn get_repeat(
+    map: &Map,
+    start: Node,
+    mut directions: impl Iterator<Item = Direction>,
+    direction_count: u64,
+) -> anyhow::Result<u64> {
+    let mut path = vec![start];
+
+    let repeat;
+
+    loop {
+        let dir = directions.next().context("No directions")?;
+        let next = map.lookup(*path.last().context("Path empty")?, dir)?;
+
+        if let Some(i) = path.iter().position(|node| *node == next) {
+            repeat = path.len() - i;
+            break;
+        }
+
+        path.push(next);
+    }
+
+    lcm(&[repeat as u64, direction_count])
+}
+
+fn prime_factors(mut n: u64) -> HashMap<u64, u32> {
+    let mut factors = HashMap::new();
+    let mut i = 2;
+
+    while i * i <= n {
+        if n.is_multiple_of(i) {
+            n /= i;
+            *factors.entry(i).or_default() += 1;
+        } else {
+            i += 1;
+        }
+    }
+
+    // Whatever is left has no factors below its square root, so is prime
+    if n > 1 {
+        *factors.entry(n).or_default() += 1;
+    }
+
+    factors
+}
+
+fn lcm(ns: &[u64]) -> anyhow::Result<u64> {
+    ns.iter()
+        .copied()
+        .map(prime_factors)
+        .reduce(|mut acc, map| {
+            for (k, v) in map {
+                let old = acc.entry(k).or_default();
+                *old = v.max(*old);
+            }
+
+            acc
+        })
+        .context("No nodes")?
+        .into_iter()
+        .map(|(n, e)| n.pow(e))
+        .reduce(|acc, n| acc * n)
+        .context("No nodes")
+}
+
+pub struct Day {
+    instructions: Vec<Direction>,
+    map: Map,
+}
+
+impl Solution for Day {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        let mut lines = input.lines();
+
+        let first = lines.next().unwrap_or(input);
+        let instructions = first
+            .char_indices()
+            .map(|(i, c)| first[i..i + c.len_utf8()].parse())
+            .collect::<Result<Vec<Direction>, _>>()?;
+
+        if instructions.is_empty() {
+            return Err(ParseError::new(first, "at least one instruction").into());
+        }
+
+        let map = Map::from_inner(
+            lines
+                .skip(1)
+                .map(|line| -> Result<_, ParseError> {
+                    let (node, line) = parse::split_once(line, " = (")?;
+                    let (left, line) = parse::split_once(line, ", ")?;
+                    let right = parse::strip_suffix(line, ")")?;
+
+                    Ok((Node::new(node)?, (Node::new(left)?, Node::new(right)?)))
+                })
+                .collect::<Result<MapInner, _>>()?,
+        );
+
+        Ok(Self { instructions, map })
+    }
+
+    fn part1(&self) -> anyhow::Result<u64> {
+        let mut directions = self.instructions.iter().copied().cycle();
+
+        let mut i: u64 = 0;
+        let mut node = Node::start();
+
+        while !node.is_end() {
+            node = self.map.lookup(
+                node,
+                directions
+                    .next()
+                    .context("Ran out of infinite instruction")?,
+            )?;
+
+            i += 1;
+        }
+
+        Ok(i)
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        let dir_count = self.instructions.len();
+        let directions = self.instructions.iter().copied().cycle();
+
+        lcm(&self
+            .map
+            .map
+            .keys()
+            .copied()
+            .filter(|node| node.is_start_2())
+            .map(|node| get_repeat(&self.map, node, directions.clone(), dir_count as u64))
+            .collect::<anyhow::Result<Vec<_>>>()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example.txt"))?;
+
+        assert_eq!(day.part1()?, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example2.txt"))?;
+
+        assert_eq!(day.part1()?, 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_3() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example3.txt"))?;
+
+        assert_eq!(day.part2()?, 6);
+        assert_eq!(
+            day.part1().map_err(|e| e.to_string()),
+            Err("No node AAA".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_instructions() {
+        let error = Day::parse("\n\nAAA = (AAA, AAA)\n").err();
+
+        assert_eq!(
+            error
+                .and_then(|e| e.downcast::<ParseError>().ok())
+                .map(|e| e.expected),
+            Some("at least one instruction".to_string())
+        );
+    }
+
+    #[test]
+    fn input() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../input.txt"))?;
+
+        assert_eq!(day.part1()?, 17141);
+        assert_eq!(day.part2()?, 10_818_234_074_807);
+
+        Ok(())
+    }
+}
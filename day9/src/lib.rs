@@ -2,13 +2,15 @@
 #![allow(
     clippy::module_inception,
     clippy::module_name_repetitions,
+    clippy::missing_errors_doc,
     clippy::cast_precision_loss,
     clippy::cast_possible_truncation,
     clippy::cast_sign_loss
 )]
 
-use std::str::FromStr;
+use aoc_common::{parse, Solution};
 
+#[derive(Clone)]
 struct Sequence {
     pub diffs: Vec<Vec<i64>>,
 }
@@ -81,30 +83,63 @@ fn calc_diff(vals: &[i64]) -> Vec<i64> {
     out
 }
 
-const PART1: bool = false;
+pub struct Day {
+    sequences: Vec<Sequence>,
+}
 
-fn main() -> anyhow::Result<()> {
-    let input = std::fs::read_to_string("input.txt")?;
+impl Solution for Day {
+    type Answer1 = i64;
+    type Answer2 = i64;
 
-    let sum: i64 = input
-        .lines()
-        .filter_map(|line| -> Option<_> {
-            line.split_whitespace()
-                .map(i64::from_str)
-                .collect::<Result<Vec<i64>, _>>()
-                .ok()
-        })
-        .map(Sequence::new)
-        .map(|mut seq| {
-            if PART1 {
-                seq.predict()
-            } else {
-                seq.predict_back()
-            }
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            sequences: input
+                .lines()
+                .map(|line| parse::numbers(line).map(Sequence::new))
+                .collect::<Result<_, _>>()?,
         })
-        .sum();
+    }
+
+    fn part1(&self) -> anyhow::Result<i64> {
+        Ok(self
+            .sequences
+            .iter()
+            .cloned()
+            .map(|mut seq| seq.predict())
+            .sum())
+    }
+
+    fn part2(&self) -> anyhow::Result<i64> {
+        Ok(self
+            .sequences
+            .iter()
+            .cloned()
+            .map(|mut seq| seq.predict_back())
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../example.txt"))?;
+
+        assert_eq!(day.part1()?, 114);
+        assert_eq!(day.part2()?, 2);
 
-    println!("{sum}");
+        Ok(())
+    }
+
+    #[test]
+    fn input() -> anyhow::Result<()> {
+        let day = Day::parse(include_str!("../input.txt"))?;
 
-    Ok(())
+        assert_eq!(day.part1()?, 1_731_106_378);
+        assert_eq!(day.part2()?, 1087);
+
+        Ok(())
+    }
 }